@@ -39,6 +39,8 @@ async fn main() -> Result<()> {
             MediaCollection::Concert(concert) => {
                 println!("Browse returned concert {}", concert.title)
             }
+            // media types which might be added in the future
+            media => println!("Browse returned media {}", media.as_media_base().title),
         }
     }
 
@@ -196,7 +196,7 @@ impl Account {
                 "current_password": current_password,
                 "new_email": new_email,
             }))
-            .request()
+            .request::<()>()
             .await?;
         Ok(())
     }
@@ -207,7 +207,7 @@ impl Account {
         self.executor
             .patch(endpoint)
             .json(&json!({"wallpaper": &wallpaper.name}))
-            .request()
+            .request::<()>()
             .await?;
         self.wallpaper = wallpaper;
         Ok(())
@@ -320,5 +320,94 @@ mod wallpaper {
     }
 }
 
+mod subscription {
+    use crate::{enum_values, Crunchyroll, Request, Result};
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+
+    enum_values! {
+        /// Status of a [`Subscription`]. [`SubscriptionStatus::InGrace`] means that the last
+        /// payment failed but the subscription is still usable until the grace period ends.
+        pub enum SubscriptionStatus {
+            Active = "active"
+            InGrace = "in_grace"
+            OnHold = "on_hold"
+            Cancelled = "cancelled"
+            Expired = "expired"
+        }
+    }
+
+    /// A subscription of the currently logged in account.
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault, Request)]
+    #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct Subscription {
+        pub id: String,
+        /// Tier of the subscription, e.g. `fan` or `mega_fan`.
+        pub tier: String,
+        /// Where the subscription was bought, e.g. `recurly`, `google` or `apple`.
+        pub source: String,
+        pub status: SubscriptionStatus,
+
+        /// If the subscription is currently in its free trial and no payment has been made yet.
+        #[serde(alias = "active_free_trial")]
+        pub is_free_trial: bool,
+        pub auto_renew: bool,
+        pub cycle_duration: String,
+        pub currency_code: String,
+        pub amount: f64,
+
+        #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
+        pub effective_date: DateTime<Utc>,
+        pub next_renewal_date: Option<DateTime<Utc>>,
+
+        #[cfg(feature = "__test_strict")]
+        #[serde(flatten)]
+        unknown: std::collections::HashMap<String, crate::StrictValue>,
+    }
+
+    impl Subscription {
+        /// If the last payment failed and the subscription is only active until the payment grace
+        /// period ends.
+        pub fn is_in_grace_period(&self) -> bool {
+            self.status == SubscriptionStatus::InGrace
+        }
+    }
+
+    #[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault, Request)]
+    #[request(executor(subscriptions))]
+    #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    struct SubscriptionResult {
+        subscriptions: Vec<Subscription>,
+
+        #[cfg(feature = "__test_strict")]
+        #[serde(flatten)]
+        unknown: std::collections::HashMap<String, crate::StrictValue>,
+    }
+
+    impl Crunchyroll {
+        /// Return the subscriptions of the current account. Unlike [`Crunchyroll::premium`], which
+        /// only knows if the account has premium access, the returned [`Subscription`]s tell if the
+        /// access comes from a free trial ([`Subscription::is_free_trial`]) or if the subscription
+        /// is in a payment grace period ([`Subscription::is_in_grace_period`]). Accounts without
+        /// any subscription return an empty vec.
+        pub async fn subscriptions(&self) -> Result<Vec<Subscription>> {
+            let endpoint = format!(
+                "https://www.crunchyroll.com/subs/v3/subscriptions/{}",
+                self.executor.details.account_id.clone()?
+            );
+            Ok(self
+                .executor
+                .get(endpoint)
+                .request::<SubscriptionResult>()
+                .await?
+                .subscriptions)
+        }
+    }
+}
+
 use crate::crunchyroll::MaturityRating;
+pub use subscription::*;
 pub use wallpaper::*;
@@ -95,6 +95,20 @@ pub struct CategoryInformation {
 }
 
 impl CategoryInformation {
+    /// The localized name of the category, in the locale the [`Crunchyroll`] instance was
+    /// created / set with.
+    pub fn name(&self) -> &str {
+        &self.localization.title
+    }
+
+    /// The background image of the category with the highest resolution, if any.
+    pub fn background_image(&self) -> Option<&Image> {
+        self.images
+            .background
+            .iter()
+            .max_by_key(|image| image.width)
+    }
+
     /// Get all sub-categories of this category.
     pub async fn sub_categories(&self) -> Result<Vec<SubCategoryInformation>> {
         let endpoint = format!(
@@ -127,6 +141,14 @@ pub struct SubCategoryInformation {
     pub localization: CategoryInformationLocalization,
 }
 
+impl SubCategoryInformation {
+    /// The localized name of the sub category, in the locale the [`Crunchyroll`] instance was
+    /// created / set with.
+    pub fn name(&self) -> &str {
+        &self.localization.title
+    }
+}
+
 impl Crunchyroll {
     /// Returns all video categories. Note that not all categories declared in [`Category`] are
     /// returned since some of them are sub-categories. Call [`Category::sub_categories`] to get a
@@ -1,9 +1,10 @@
 //! Commonly used types.
 
-use crate::{Executor, Result};
+use crate::{Crunchyroll, Executor, Result};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Debug, Formatter};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -58,6 +59,28 @@ pub(crate) struct PaginationOptions {
     pub(crate) extra: BTreeMap<&'static str, String>,
 }
 
+impl PaginationOptions {
+    /// Page size and offset query parameters of endpoints which are paginated by offset, see
+    /// [`offset_query`].
+    pub(crate) fn offset_query(&self) -> [(&'static str, u32); 2] {
+        offset_query(self.page_size, self.start)
+    }
+
+    /// Page and page size query parameters of endpoints which are paginated by pages, e.g. the
+    /// talkbox (comments, reviews) and watch history endpoints.
+    pub(crate) fn page_query(&self) -> [(&'static str, u32); 2] {
+        [("page", self.page), ("page_size", self.page_size)]
+    }
+}
+
+/// Page size and offset query parameters of endpoints which are paginated by offset. All
+/// `content/v2/discover` endpoints (browse, search, similar, watchlist, ...) expect `n` as page size
+/// parameter, an unknown parameter (e.g. `limit`) is silently ignored and the default page size of
+/// the endpoint is used instead.
+pub(crate) fn offset_query(page_size: u32, start: u32) -> [(&'static str, u32); 2] {
+    [("n", page_size), ("start", start)]
+}
+
 /// Crunchyroll doesn't always deliver the correct number of total elements on pagination endpoints.
 /// Sometimes it also delivers a link which refers to the next page which can be used to indicate if
 /// more pages are existing. This enum stores if more pages existing by looking up if the link is
@@ -132,6 +155,18 @@ pub struct Pagination<T: Default + DeserializeOwned + Request> {
     next_type: Option<PaginationNextType>,
 }
 
+impl<T: Debug + Default + DeserializeOwned + Request> Debug for Pagination<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pagination")
+            .field("data", &self.data)
+            .field("start", &self.paginator_options.start)
+            .field("page", &self.paginator_options.page)
+            .field("page_size", &self.paginator_options.page_size)
+            .field("count", &self.count)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<T: Default + DeserializeOwned + Request> Stream for Pagination<T> {
     type Item = Result<T>;
 
@@ -226,6 +261,32 @@ impl<T: Default + DeserializeOwned + Request> Pagination<T> {
         self.paginator_options.page_size = size
     }
 
+    /// Return the current position of the pagination. Use [`Crunchyroll::resume_pagination`] to
+    /// continue the pagination from this position later on.
+    pub fn cursor(&self) -> PaginationCursor {
+        let options = &self.paginator_options;
+        PaginationCursor {
+            count: self.count,
+            // the last requested page isn't completely returned if items of it are still buffered
+            page: if self.data.is_empty() {
+                options.page
+            } else {
+                options.page.saturating_sub(1)
+            },
+            page_size: options.page_size,
+            total: match self.next_type {
+                Some(PaginationNextType::Total(total)) => Some(total),
+                _ => None,
+            },
+            query: options.query.clone(),
+            extra: options
+                .extra
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        }
+    }
+
     /// Return the total amount of items which can be fetched. Is [`Some`] if the total amount is
     /// known, else [`None`] (Crunchyroll has two different pagination implementations, one doesn't
     /// report the total amount).
@@ -241,6 +302,57 @@ impl<T: Default + DeserializeOwned + Request> Pagination<T> {
     }
 }
 
+/// Serializable position of a [`Pagination`], returned by [`Pagination::cursor`]. Can be stored
+/// to resume the pagination later on with [`Crunchyroll::resume_pagination`], e.g. after the
+/// process got interrupted.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaginationCursor {
+    /// Number of items which were returned by the pagination.
+    pub count: u32,
+    /// Number of pages which were completely returned by the pagination.
+    pub page: u32,
+    pub page_size: u32,
+    /// Total number of items, if it was already known when the cursor was created.
+    pub total: Option<u32>,
+
+    query: Vec<(String, String)>,
+    extra: Vec<(String, String)>,
+}
+
+impl Crunchyroll {
+    /// Resume a pagination at the position of the given cursor (created with
+    /// [`Pagination::cursor`]). The function which fetches the pages can't be serialized, so
+    /// `pagination` must be a new instance, created by the same function which was used to create
+    /// the pagination the cursor was taken from. Its query (e.g. the options given to
+    /// [`Crunchyroll::browse`]) is replaced with the one stored in the cursor.
+    /// This only works reliable with offset based endpoints (which are most of them); for the
+    /// others the resumed pagination may return items again which were already returned before.
+    pub fn resume_pagination<T: Default + DeserializeOwned + Request>(
+        &self,
+        mut pagination: Pagination<T>,
+        cursor: PaginationCursor,
+    ) -> Pagination<T> {
+        pagination.data.clear();
+        pagination.next_state = None;
+        pagination.next_type = None;
+        pagination.count = cursor.count;
+
+        let options = &mut pagination.paginator_options;
+        options.executor = self.executor.clone();
+        options.start = cursor.count;
+        options.page = cursor.page;
+        options.page_size = cursor.page_size;
+        options.query = cursor.query;
+        for (key, value) in cursor.extra {
+            if let Some(extra_value) = options.extra.get_mut(key.as_str()) {
+                *extra_value = value
+            }
+        }
+
+        pagination
+    }
+}
+
 /// Contains a variable amount of items and the maximum / total of item which are available.
 /// Mostly used when fetching pagination results.
 #[derive(Clone, Debug, Deserialize, smart_default::SmartDefault, Request)]
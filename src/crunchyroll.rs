@@ -72,7 +72,7 @@ impl Locale {
             Locale::tr_TR,
             Locale::vi_VN,
             Locale::zh_CN,
-            Locale::zh_CN,
+            Locale::zh_HK,
             Locale::zh_TW,
         ]
     }
@@ -110,6 +110,104 @@ impl Locale {
         }
         .to_string()
     }
+
+    /// The ISO-639 language code of the locale, e.g. `ja` for [`Locale::ja_JP`]. For
+    /// [`Locale::Custom`] this is the first subtag of the custom value.
+    pub fn language_code(&self) -> &str {
+        match self {
+            Locale::ar_ME | Locale::ar_SA => "ar",
+            Locale::ca_ES => "ca",
+            Locale::de_DE => "de",
+            Locale::en_IN | Locale::en_US => "en",
+            Locale::es_419 | Locale::es_ES | Locale::es_LA => "es",
+            Locale::fr_FR => "fr",
+            Locale::hi_IN => "hi",
+            Locale::id_ID => "id",
+            Locale::it_IT => "it",
+            Locale::ja_JP => "ja",
+            Locale::ko_KR => "ko",
+            Locale::ms_MY => "ms",
+            Locale::pl_PL => "pl",
+            Locale::pt_BR | Locale::pt_PT => "pt",
+            Locale::ru_RU => "ru",
+            Locale::ta_IN => "ta",
+            Locale::te_IN => "te",
+            Locale::th_TH => "th",
+            Locale::tr_TR => "tr",
+            Locale::vi_VN => "vi",
+            Locale::zh_CN | Locale::zh_HK | Locale::zh_TW => "zh",
+            Locale::Custom(custom) => custom.split(['-', '_']).next().unwrap_or_default(),
+        }
+    }
+
+    /// The ISO-3166 region code of the locale, e.g. `JP` for [`Locale::ja_JP`]. Crunchyroll uses
+    /// some non-standard regions: [`Locale::es_LA`] (Latin America) returns the UN M.49 code `419`
+    /// like [`Locale::es_419`], and [`Locale::ar_ME`] (generic arabic) has no region. For
+    /// [`Locale::Custom`] the first two letter or three digit subtag after the language is used,
+    /// if present.
+    pub fn region_code(&self) -> Option<&str> {
+        Some(match self {
+            Locale::ar_ME => return None,
+            Locale::ar_SA => "SA",
+            Locale::ca_ES | Locale::es_ES => "ES",
+            Locale::de_DE => "DE",
+            Locale::en_IN | Locale::hi_IN | Locale::ta_IN | Locale::te_IN => "IN",
+            Locale::en_US => "US",
+            Locale::es_419 | Locale::es_LA => "419",
+            Locale::fr_FR => "FR",
+            Locale::id_ID => "ID",
+            Locale::it_IT => "IT",
+            Locale::ja_JP => "JP",
+            Locale::ko_KR => "KR",
+            Locale::ms_MY => "MY",
+            Locale::pl_PL => "PL",
+            Locale::pt_BR => "BR",
+            Locale::pt_PT => "PT",
+            Locale::ru_RU => "RU",
+            Locale::th_TH => "TH",
+            Locale::tr_TR => "TR",
+            Locale::vi_VN => "VN",
+            Locale::zh_CN => "CN",
+            Locale::zh_HK => "HK",
+            Locale::zh_TW => "TW",
+            Locale::Custom(custom) => custom.split(['-', '_']).skip(1).find(|subtag| {
+                (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+                    || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+            })?,
+        })
+    }
+
+    /// Convert a BCP-47 language tag (e.g. `ja-JP`) to a [`Locale`]. Matching is case-insensitive,
+    /// `_` is accepted as separator and script subtags (like `Hant` in `zh-Hant-TW`) are ignored.
+    /// If the tag only consists of a language, the locale is returned if it's the only one with
+    /// this language (or the one without a region). Tags which do not match any known locale are
+    /// returned as [`Locale::Custom`].
+    pub fn from_bcp47<S: AsRef<str>>(tag: S) -> Locale {
+        let tag = tag.as_ref().trim();
+        // crunchyroll's own tags, e.g. `es-LA`, take precedence over the standardized matching
+        let exact = Locale::from(tag.replace('_', "-"));
+        if !matches!(exact, Locale::Custom(_)) {
+            return exact;
+        }
+        let custom = Locale::Custom(tag.to_string());
+        let language = custom.language_code().to_ascii_lowercase();
+        let region = custom.region_code().map(|r| r.to_ascii_uppercase());
+
+        let candidates: Vec<Locale> = Locale::all()
+            .into_iter()
+            .filter(|l| l.language_code() == language)
+            .collect();
+        if let Some(locale) = candidates
+            .iter()
+            .find(|l| l.region_code() == region.as_deref())
+        {
+            return locale.clone();
+        }
+        if region.is_none() && candidates.len() == 1 {
+            return candidates[0].clone();
+        }
+        custom
+    }
 }
 
 enum_values! {
@@ -131,21 +229,62 @@ impl Crunchyroll {
         CrunchyrollBuilder::default()
     }
 
+    /// Create a new [`Crunchyroll`] instance which shares the given [`Executor`]. Always use this
+    /// instead of constructing the struct directly, all (mutable) session state must live in the
+    /// [`Executor`] so that every instance sharing it stays in sync, e.g. after a token refresh.
+    pub(crate) fn from_executor(executor: Arc<Executor>) -> Self {
+        Self { executor }
+    }
+
     /// Return the (cloned) [`Client`] which is internally used to make requests.
     pub fn client(&self) -> Client {
         self.executor.client.clone()
     }
 
-    /// Check if the current used account has premium.
+    /// Return the locale in which all results which have human readable text in it are returned.
+    pub fn locale(&self) -> Locale {
+        self.executor.locale()
+    }
+
+    /// Change the locale in which all results which have human readable text in it should be
+    /// returned. This affects all following requests, including the ones made by media structs
+    /// which were requested before the locale got changed.
+    pub fn set_locale(&self, locale: Locale) {
+        *self.executor.details.locale.write().unwrap() = locale
+    }
+
+    /// Check if the current used account has premium. Free trials and subscriptions in a payment
+    /// grace period count as premium too, use [`Crunchyroll::subscriptions`] to distinguish them.
     pub async fn premium(&self) -> bool {
         self.executor.premium().await
     }
 
+    /// Check if the subscription tier of the current used account allows to download media for
+    /// offline viewing. Note that this is only the account side of the check, not every media is
+    /// available offline (see e.g. [`crate::Episode::available_offline`]).
+    pub async fn can_download_offline(&self) -> bool {
+        self.executor.can_download_offline().await
+    }
+
     /// Return the current session token. It can be used to log-in later with
     /// [`CrunchyrollBuilder::login_with_refresh_token`] or [`CrunchyrollBuilder::login_with_etp_rt`].
     pub async fn session_token(&self) -> SessionToken {
         self.executor.config.read().await.session_token.clone()
     }
+
+    /// Return the cms details (bucket, policy, signature, key pair id and expiry) which are used
+    /// to sign requests. Useful for diagnosing failing requests, e.g. if the signature has
+    /// expired.
+    pub fn cms_info(&self) -> CmsInfo {
+        let details = &self.executor.details;
+        CmsInfo {
+            bucket: details.bucket.clone(),
+            policy: details.policy.clone(),
+            signature: details.signature.clone(),
+            key_pair_id: details.key_pair_id.clone(),
+            expires: details.cms_expires,
+        }
+    }
 }
 
 mod auth {
@@ -169,6 +308,18 @@ mod auth {
         Anonymous,
     }
 
+    /// The cms details which are used to sign requests to the cms endpoints. They are obtained
+    /// once at login and are not refreshed when the session token gets refreshed, so requests to
+    /// cms endpoints will fail after [`CmsInfo::expires`].
+    #[derive(Clone, Debug, Serialize)]
+    pub struct CmsInfo {
+        pub bucket: String,
+        pub policy: String,
+        pub signature: String,
+        pub key_pair_id: String,
+        pub expires: DateTime<Utc>,
+    }
+
     #[derive(Debug, Default, Deserialize)]
     #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
     #[cfg_attr(not(feature = "__test_strict"), serde(default))]
@@ -196,9 +347,12 @@ mod auth {
     }
 
     #[allow(dead_code)]
-    #[derive(Clone, Debug)]
+    #[derive(Debug)]
     pub(crate) struct ExecutorDetails {
-        pub(crate) locale: Locale,
+        /// Must be a [`std::sync::RwLock`] because it can be changed via [`Crunchyroll::set_locale`]
+        /// while the [`Executor`] is shared and it's required in sync context, e.g. in
+        /// [`ExecutorRequestBuilder::apply_locale_query`].
+        pub(crate) locale: std::sync::RwLock<Locale>,
         pub(crate) preferred_audio_locale: Option<Locale>,
 
         pub(crate) bucket: String,
@@ -206,6 +360,7 @@ mod auth {
         pub(crate) signature: String,
         pub(crate) policy: String,
         pub(crate) key_pair_id: String,
+        pub(crate) cms_expires: DateTime<Utc>,
         /// The account id is wrapped in a [`Result`] since [`Executor::auth_anonymously`] /
         /// [`CrunchyrollBuilder::login_anonymously`] doesn't return an account id and to prevent
         /// writing error messages multiple times in functions which require the account id to be
@@ -213,6 +368,15 @@ mod auth {
         pub(crate) account_id: Result<String>,
         pub(crate) device_id: Option<String>,
         pub(crate) device_type: Option<String>,
+
+        /// Query parameters which are merged into every api request. Set via
+        /// [`CrunchyrollBuilder::extra_query`].
+        pub(crate) extra_query: Vec<(String, String)>,
+
+        /// Maximum size and timeout of asset (e.g. subtitle) downloads. Set via
+        /// [`CrunchyrollBuilder::max_asset_size`] and [`CrunchyrollBuilder::asset_timeout`].
+        pub(crate) max_asset_size: usize,
+        pub(crate) asset_timeout: Option<std::time::Duration>,
     }
 
     #[cfg(feature = "experimental-stabilizations")]
@@ -330,11 +494,26 @@ mod auth {
             Ok(resp)
         }
 
+        pub(crate) fn locale(&self) -> Locale {
+            self.details.locale.read().unwrap().clone()
+        }
+
         pub(crate) async fn premium(&self) -> bool {
+            self.benefits().await.contains(&"cr_premium".to_string())
+        }
+
+        pub(crate) async fn can_download_offline(&self) -> bool {
+            self.benefits()
+                .await
+                .contains(&"offline_viewing".to_string())
+        }
+
+        /// Benefits of the subscription of the current account, extracted from the access token.
+        async fn benefits(&self) -> Vec<String> {
             let executor_config = self.config.read().await;
 
             if matches!(executor_config.session_token, SessionToken::Anonymous) {
-                return false;
+                return vec![];
             }
 
             #[derive(Deserialize)]
@@ -351,7 +530,6 @@ mod auth {
                 .unwrap()
                 .claims
                 .benefits
-                .contains(&"cr_premium".to_string())
         }
 
         async fn auth_anonymously(client: &Client) -> Result<AuthResponse> {
@@ -469,15 +647,19 @@ mod auth {
                     session_expire: Default::default(),
                 }),
                 details: ExecutorDetails {
-                    locale: Default::default(),
+                    locale: std::sync::RwLock::new(Default::default()),
                     preferred_audio_locale: None,
                     bucket: "".to_string(),
                     signature: "".to_string(),
                     policy: "".to_string(),
                     key_pair_id: "".to_string(),
+                    cms_expires: DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH),
                     account_id: Ok("".to_string()),
                     device_id: None,
                     device_type: None,
+                    extra_query: vec![],
+                    max_asset_size: DEFAULT_MAX_ASSET_SIZE,
+                    asset_timeout: None,
                 },
                 #[cfg(feature = "tower")]
                 middleware: None,
@@ -490,6 +672,9 @@ mod auth {
         }
     }
 
+    /// Default of [`CrunchyrollBuilder::max_asset_size`].
+    const DEFAULT_MAX_ASSET_SIZE: usize = 8 * 1024 * 1024;
+
     pub(crate) struct ExecutorRequestBuilder {
         executor: Arc<Executor>,
         builder: RequestBuilder,
@@ -507,7 +692,7 @@ mod auth {
         }
 
         pub(crate) fn apply_locale_query(self) -> ExecutorRequestBuilder {
-            let locale = self.executor.details.locale.clone();
+            let locale = self.executor.locale();
             self.query(&[("locale", locale)])
         }
 
@@ -525,23 +710,110 @@ mod auth {
             self
         }
 
+        /// Merges [`ExecutorDetails::extra_query`] into the request. Parameters whose key is
+        /// already set by the request itself are skipped, so method specific parameters always
+        /// take precedence. Only requests to the api host get the parameters, requests to cdns
+        /// (playlists, segments, subtitles, images, ...) are left untouched as their urls are
+        /// often signed and unknown parameters may invalidate the signature.
+        fn apply_extra_query(self) -> Result<RequestBuilder> {
+            let extra_query = &self.executor.details.extra_query;
+            if extra_query.is_empty() {
+                return Ok(self.builder);
+            }
+
+            let (client, req) = self.builder.build_split();
+            let mut req = req?;
+            if req.url().host_str() != Some("www.crunchyroll.com") {
+                return Ok(RequestBuilder::from_parts(client, req));
+            }
+            let existing_keys: Vec<String> = req
+                .url()
+                .query_pairs()
+                .map(|(key, _)| key.into_owned())
+                .collect();
+            {
+                let mut query_pairs = req.url_mut().query_pairs_mut();
+                for (key, value) in extra_query {
+                    if !existing_keys.contains(key) {
+                        query_pairs.append_pair(key, value);
+                    }
+                }
+            }
+            Ok(RequestBuilder::from_parts(client, req))
+        }
+
         pub(crate) async fn request<T: Request + DeserializeOwned>(self) -> Result<T> {
-            self.executor.request(self.builder).await
+            let executor = self.executor.clone();
+            executor.request(self.apply_extra_query()?).await
         }
 
         pub(crate) async fn request_raw(self) -> Result<Vec<u8>> {
+            Ok(self.send().await?.bytes().await?.to_vec())
+        }
+
+        /// Send the request and return the url the response came from, after all redirects were
+        /// followed. Returns [`Error::Request`] if the final response doesn't have a success
+        /// status.
+        #[cfg(feature = "parse")]
+        pub(crate) async fn request_redirect_url(self) -> Result<reqwest::Url> {
+            let resp = self.send().await?;
+            let url = resp.url().clone();
+            if !resp.status().is_success() {
+                return Err(Error::Request {
+                    message: format!("request failed with status {}", resp.status()),
+                    status: Some(resp.status()),
+                    url: url.to_string(),
+                    connection_failed: false,
+                });
+            }
+            Ok(url)
+        }
+
+        /// Like [`ExecutorRequestBuilder::request_raw`] but for assets like subtitles, which are
+        /// not delivered by the api but a cdn. The request is aborted with [`Error::Decode`] if
+        /// the response body is larger than [`ExecutorDetails::max_asset_size`], and with a
+        /// timeout error if it takes longer than [`ExecutorDetails::asset_timeout`].
+        pub(crate) async fn request_asset(mut self) -> Result<Vec<u8>> {
+            let max_size = self.executor.details.max_asset_size;
+            if let Some(timeout) = self.executor.details.asset_timeout {
+                self.builder = self.builder.timeout(timeout)
+            }
+
+            let mut resp = self.send().await?;
+            let url = resp.url().to_string();
+            let too_large = |content: Vec<u8>| Error::Decode {
+                message: format!(
+                    "response body exceeds the maximum asset size of {max_size} bytes"
+                ),
+                content,
+                url: url.clone(),
+            };
+
+            if resp
+                .content_length()
+                .is_some_and(|length| length > max_size as u64)
+            {
+                return Err(too_large(vec![]));
+            }
+            let mut body = vec![];
+            while let Some(chunk) = resp.chunk().await? {
+                if body.len() + chunk.len() > max_size {
+                    return Err(too_large(body));
+                }
+                body.extend_from_slice(&chunk)
+            }
+            Ok(body)
+        }
+
+        async fn send(self) -> Result<reqwest::Response> {
+            #[cfg(feature = "tower")]
+            let executor = self.executor.clone();
+            let builder = self.apply_extra_query()?;
             #[cfg(feature = "tower")]
-            if let Some(middleware) = &self.executor.middleware {
-                return Ok(middleware
-                    .lock()
-                    .await
-                    .call(self.builder.build()?)
-                    .await?
-                    .bytes()
-                    .await?
-                    .to_vec());
+            if let Some(middleware) = &executor.middleware {
+                return middleware.lock().await.call(builder.build()?).await;
             }
-            Ok(self.builder.send().await?.bytes().await?.to_vec())
+            Ok(builder.send().await?)
         }
     }
 
@@ -549,9 +821,16 @@ mod auth {
     /// [`Crunchyroll::builder`].
     pub struct CrunchyrollBuilder {
         client: Client,
+        /// If [`CrunchyrollBuilder::client`] was used to set a custom client.
+        custom_client: bool,
+        pool_max_idle_per_host: Option<usize>,
+        http2_prior_knowledge: bool,
         locale: Locale,
         preferred_audio_locale: Option<Locale>,
         device_identifier: Option<(String, String)>,
+        extra_query: Vec<(String, String)>,
+        max_asset_size: usize,
+        asset_timeout: Option<std::time::Duration>,
 
         #[cfg(feature = "tower")]
         middleware: Option<tokio::sync::Mutex<crate::internal::tower::Middleware>>,
@@ -565,9 +844,15 @@ mod auth {
                 client: CrunchyrollBuilder::predefined_client_builder()
                     .build()
                     .unwrap(),
+                custom_client: false,
+                pool_max_idle_per_host: None,
+                http2_prior_knowledge: false,
                 locale: Locale::en_US,
                 preferred_audio_locale: None,
                 device_identifier: None,
+                extra_query: vec![],
+                max_asset_size: DEFAULT_MAX_ASSET_SIZE,
+                asset_timeout: None,
                 #[cfg(feature = "tower")]
                 middleware: None,
                 #[cfg(feature = "experimental-stabilizations")]
@@ -586,7 +871,25 @@ mod auth {
         /// amount everything goes back to normal and works as it should). You can use this builder
         /// to configure the behavior of the download client. Use [`CrunchyrollBuilder::client`] or
         /// to set your built client.
+        /// The tls backend depends on the enabled features: if `rustls-tls` is enabled (the
+        /// default), a preconfigured [`rustls`] backend is used, otherwise if `native-tls` is
+        /// enabled, the platform native tls backend is used.
         pub fn predefined_client_builder() -> ClientBuilder {
+            let builder = Client::builder()
+                .https_only(true)
+                .cookie_store(true)
+                .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36 Edg/114.0.1823.67a");
+
+            #[cfg(feature = "rustls-tls")]
+            let builder = builder.use_preconfigured_tls(Self::rustls_config());
+            #[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+            let builder = builder.use_native_tls();
+
+            builder
+        }
+
+        #[cfg(feature = "rustls-tls")]
+        fn rustls_config() -> rustls::ClientConfig {
             let mut root_store = rustls::RootCertStore::empty();
             root_store.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|ta| {
                 rustls::OwnedTrustAnchor::from_subject_spki_name_constraints(
@@ -595,19 +898,13 @@ mod auth {
                     ta.name_constraints.clone().map(|nc| nc.to_vec()),
                 )
             }));
-            let tls_config = rustls::ClientConfig::builder()
+            rustls::ClientConfig::builder()
                 .with_cipher_suites(rustls::DEFAULT_CIPHER_SUITES)
                 .with_kx_groups(&[&rustls::kx_group::X25519])
                 .with_protocol_versions(&[&rustls::version::TLS12, &rustls::version::TLS13])
                 .unwrap()
                 .with_root_certificates(root_store)
-                .with_no_client_auth();
-
-            Client::builder()
-                .https_only(true)
-                .cookie_store(true)
-                .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36 Edg/114.0.1823.67a")
-                .use_preconfigured_tls(tls_config)
+                .with_no_client_auth()
         }
 
         /// Set a custom client that will be used in all api requests.
@@ -616,9 +913,46 @@ mod auth {
         /// which may be needed to make successful requests to Crunchyroll.
         pub fn client(mut self, client: Client) -> CrunchyrollBuilder {
             self.client = client;
+            self.custom_client = true;
             self
         }
 
+        /// Set the maximum number of idle connections per host the client keeps open. Useful to
+        /// tune the client when doing many requests in parallel. Not set by default (= no limit).
+        /// Has no effect if a custom client is set with [`CrunchyrollBuilder::client`], set
+        /// [`ClientBuilder::pool_max_idle_per_host`] on it directly instead.
+        pub fn pool_max_idle_per_host(mut self, max: usize) -> CrunchyrollBuilder {
+            self.pool_max_idle_per_host = Some(max);
+            self
+        }
+
+        /// Only use HTTP/2 (without trying HTTP/1 first) for requests. Disabled by default.
+        /// Has no effect if a custom client is set with [`CrunchyrollBuilder::client`], set
+        /// [`ClientBuilder::http2_prior_knowledge`] on it directly instead.
+        pub fn http2_prior_knowledge(mut self, enable: bool) -> CrunchyrollBuilder {
+            self.http2_prior_knowledge = enable;
+            self
+        }
+
+        /// Rebuild the client from [`CrunchyrollBuilder::predefined_client_builder`] with the
+        /// configured client options. Does nothing if a custom client is used or no option is set.
+        fn apply_client_options(&mut self) {
+            if self.custom_client
+                || (self.pool_max_idle_per_host.is_none() && !self.http2_prior_knowledge)
+            {
+                return;
+            }
+
+            let mut builder = CrunchyrollBuilder::predefined_client_builder();
+            if let Some(max) = self.pool_max_idle_per_host {
+                builder = builder.pool_max_idle_per_host(max)
+            }
+            if self.http2_prior_knowledge {
+                builder = builder.http2_prior_knowledge()
+            }
+            self.client = builder.build().unwrap()
+        }
+
         /// Set in which languages all results which have human readable text in it should be
         /// returned.
         pub fn locale(mut self, locale: Locale) -> CrunchyrollBuilder {
@@ -652,6 +986,36 @@ mod auth {
             self
         }
 
+        /// Set query parameters which are added to every api request. This is an escape hatch for
+        /// parameters Crunchyroll expects (e.g. experiment or feature flags) which this crate
+        /// doesn't support (yet). Parameters set by the request itself, like `locale` or
+        /// `preferred_audio_language`, always take precedence: if a request already has a
+        /// parameter with the same key, the extra parameter is not added to it.
+        ///
+        /// The parameters are only added to requests to the api (`www.crunchyroll.com`), not to
+        /// requests to cdns or static assets like stream playlists, segments, subtitles or
+        /// images. Their urls are often signed and additional parameters could invalidate the
+        /// signature or bypass caching.
+        pub fn extra_query(mut self, query: Vec<(String, String)>) -> CrunchyrollBuilder {
+            self.extra_query = query;
+            self
+        }
+
+        /// Set the maximum size in bytes an asset download (e.g. of a [`crate::media::Subtitle`])
+        /// may have. Downloads which exceed it are aborted with [`Error::Decode`]. This protects
+        /// against broken responses blowing up memory usage. Default is 8 MiB.
+        pub fn max_asset_size(mut self, max_size: usize) -> CrunchyrollBuilder {
+            self.max_asset_size = max_size;
+            self
+        }
+
+        /// Set a timeout for asset downloads (e.g. of a [`crate::media::Subtitle`]). Not set by
+        /// default, so only the timeout of the client applies.
+        pub fn asset_timeout(mut self, timeout: std::time::Duration) -> CrunchyrollBuilder {
+            self.asset_timeout = Some(timeout);
+            self
+        }
+
         /// Adds a [tower](https://docs.rs/tower/latest/tower/) middleware which is called on every
         /// request.
         #[cfg(feature = "tower")]
@@ -698,7 +1062,7 @@ mod auth {
 
         /// Login without an account. This is just like if you would visit crunchyroll.com without
         /// an account. Some functions won't work if logged in with this method.
-        pub async fn login_anonymously(self) -> Result<Crunchyroll> {
+        pub async fn login_anonymously(mut self) -> Result<Crunchyroll> {
             self.pre_login().await?;
 
             let login_response = Executor::auth_anonymously(&self.client).await?;
@@ -709,7 +1073,7 @@ mod auth {
 
         /// Logs in with credentials (email and password) and returns a new `Crunchyroll` instance.
         pub async fn login_with_credentials<S: AsRef<str>>(
-            self,
+            mut self,
             email: S,
             password: S,
         ) -> Result<Crunchyroll> {
@@ -740,7 +1104,7 @@ mod auth {
         /// internal they're different. I had issues when I tried to log in with the refresh token
         /// on [`CrunchyrollBuilder::login_with_etp_rt`] and vice versa.
         pub async fn login_with_refresh_token<S: AsRef<str>>(
-            self,
+            mut self,
             refresh_token: S,
         ) -> Result<Crunchyroll> {
             self.pre_login().await?;
@@ -768,7 +1132,7 @@ mod auth {
         /// [`CrunchyrollBuilder::login_with_refresh_token`] are having the same syntax, Crunchyroll
         /// internal they're different. I had issues when I tried to log in with the `etp_rt`
         /// cookie on [`CrunchyrollBuilder::login_with_refresh_token`] and vice versa.
-        pub async fn login_with_etp_rt<S: AsRef<str>>(self, etp_rt: S) -> Result<Crunchyroll> {
+        pub async fn login_with_etp_rt<S: AsRef<str>>(mut self, etp_rt: S) -> Result<Crunchyroll> {
             self.pre_login().await?;
 
             let login_response = Executor::auth_with_etp_rt(
@@ -787,7 +1151,9 @@ mod auth {
             self.post_login(login_response, session_token).await
         }
 
-        async fn pre_login(&self) -> Result<()> {
+        async fn pre_login(&mut self) -> Result<()> {
+            self.apply_client_options();
+
             // Request the index page to set cookies which are required to bypass the cloudflare bot
             // check
             self.client
@@ -845,54 +1211,55 @@ mod auth {
             )
             .await?;
 
-            let crunchy = Crunchyroll {
-                executor: Arc::new(Executor {
-                    client: self.client,
-
-                    config: RwLock::new(ExecutorConfig {
-                        token_type: login_response.token_type,
-                        access_token: login_response.access_token,
-                        session_token,
-                        session_expire: Utc::now()
-                            .add(Duration::try_seconds(login_response.expires_in as i64).unwrap()),
-                    }),
-                    details: ExecutorDetails {
-                        locale: self.locale,
-                        preferred_audio_locale: self.preferred_audio_locale,
-
-                        // '/' is trimmed so that urls which require it must be in .../{bucket}/... like format.
-                        // this just looks cleaner
-                        bucket: index
-                            .cms_web
-                            .bucket
-                            .strip_prefix('/')
-                            .unwrap_or(index.cms_web.bucket.as_str())
-                            .to_string(),
-
-                        signature: index.cms_web.signature,
-                        policy: index.cms_web.policy,
-                        key_pair_id: index.cms_web.key_pair_id,
-                        account_id: login_response.account_id.ok_or_else(|| {
-                            Error::Authentication {
-                                message: "Login with a user account to use this function"
-                                    .to_string(),
-                            }
-                        }),
-                        device_id: self
-                            .device_identifier
-                            .as_ref()
-                            .map(|(device_id, _)| device_id.clone()),
-                        device_type: self
-                            .device_identifier
-                            .as_ref()
-                            .map(|(_, device_type)| device_type.clone()),
-                    },
-                    #[cfg(feature = "tower")]
-                    middleware: self.middleware,
-                    #[cfg(feature = "experimental-stabilizations")]
-                    fixes: self.fixes,
+            let crunchy = Crunchyroll::from_executor(Arc::new(Executor {
+                client: self.client,
+
+                config: RwLock::new(ExecutorConfig {
+                    token_type: login_response.token_type,
+                    access_token: login_response.access_token,
+                    session_token,
+                    session_expire: Utc::now()
+                        .add(Duration::try_seconds(login_response.expires_in as i64).unwrap()),
                 }),
-            };
+                details: ExecutorDetails {
+                    locale: std::sync::RwLock::new(self.locale),
+                    preferred_audio_locale: self.preferred_audio_locale,
+
+                    // '/' is trimmed so that urls which require it must be in .../{bucket}/... like format.
+                    // this just looks cleaner
+                    bucket: index
+                        .cms_web
+                        .bucket
+                        .strip_prefix('/')
+                        .unwrap_or(index.cms_web.bucket.as_str())
+                        .to_string(),
+
+                    signature: index.cms_web.signature,
+                    policy: index.cms_web.policy,
+                    key_pair_id: index.cms_web.key_pair_id,
+                    cms_expires: index.cms_web.expires,
+                    account_id: login_response
+                        .account_id
+                        .ok_or_else(|| Error::Authentication {
+                            message: "Login with a user account to use this function".to_string(),
+                        }),
+                    device_id: self
+                        .device_identifier
+                        .as_ref()
+                        .map(|(device_id, _)| device_id.clone()),
+                    device_type: self
+                        .device_identifier
+                        .as_ref()
+                        .map(|(_, device_type)| device_type.clone()),
+                    extra_query: self.extra_query,
+                    max_asset_size: self.max_asset_size,
+                    asset_timeout: self.asset_timeout,
+                },
+                #[cfg(feature = "tower")]
+                middleware: self.middleware,
+                #[cfg(feature = "experimental-stabilizations")]
+                fixes: self.fixes,
+            }));
 
             Ok(crunchy)
         }
@@ -922,7 +1289,7 @@ mod auth {
 
         #[cfg(not(feature = "__test_strict"))]
         {
-            Ok(check_request(url, resp).await?)
+            check_request(url, resp).await
         }
         #[cfg(feature = "__test_strict")]
         {
@@ -932,8 +1299,8 @@ mod auth {
             let value = serde_json::Value::deserialize(serde::de::value::MapDeserializer::new(
                 cleaned.into_iter(),
             ))?;
-            serde_json::from_value(value.clone()).map_err(|e| Error::Decode {
-                message: format!("{} at {}:{}", e, e.line(), e.column()),
+            crate::error::from_value_with_path(value.clone()).map_err(|message| Error::Decode {
+                message,
                 content: value.to_string().into_bytes(),
                 url,
             })
@@ -979,4 +1346,4 @@ mod auth {
 }
 
 pub(crate) use auth::Executor;
-pub use auth::{CrunchyrollBuilder, SessionToken};
+pub use auth::{CmsInfo, CrunchyrollBuilder, SessionToken};
@@ -22,6 +22,9 @@ pub enum Error {
         status: Option<StatusCode>,
         /// The url which caused the error.
         url: String,
+        /// If the request failed before any response was received because the connection
+        /// couldn't be established or it timed out. `status` is always [`None`] then.
+        connection_failed: bool,
     },
     /// While decoding the api response body something went wrong.
     Decode {
@@ -40,6 +43,17 @@ pub enum Error {
     /// Generally malformed or invalid user input.
     Input { message: String },
 
+    /// The action requires a subscription (tier) which the current account doesn't have.
+    SubscriptionRequired { message: String },
+
+    /// The comment was already reported for the given reason by the current account, see
+    /// [`crate::rating::Comment::report`].
+    AlreadyReported { message: String },
+
+    /// The action (e.g. a download) was cancelled via a
+    /// [`crate::media::CancellationToken`].
+    Cancelled { message: String },
+
     /// When the request got blocked. Currently this only triggers when the cloudflare bot
     /// protection is detected.
     Block {
@@ -83,6 +97,9 @@ impl Display for Error {
             }
             Error::Authentication { message } => write!(f, "{message}"),
             Error::Input { message } => write!(f, "{message}"),
+            Error::SubscriptionRequired { message } => write!(f, "{message}"),
+            Error::AlreadyReported { message } => write!(f, "{message}"),
+            Error::Cancelled { message } => write!(f, "{message}"),
             Error::Block { message, body, url } => write!(f, "{message} ({url}): {body}"),
         }
     }
@@ -90,6 +107,42 @@ impl Display for Error {
 
 impl std::error::Error for Error {}
 
+impl Error {
+    /// If the error is temporary and the action which caused it might succeed if it's retried.
+    /// This is the case for server errors (status code 5xx), rate limits (status code 429) and
+    /// requests which failed because the connection couldn't be established or timed out (see
+    /// [`Error::Request`]). Other requests which failed without any response (e.g. because of a
+    /// redirect loop) aren't retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Request {
+                status,
+                connection_failed,
+                ..
+            } => {
+                *connection_failed
+                    || status.is_some_and(|status| {
+                        status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    /// If the error is caused by missing or invalid authentication. This is the case for
+    /// [`Error::Authentication`] and requests which failed with status code 401 or 403.
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            Error::Authentication { .. } => true,
+            Error::Request { status, .. } => matches!(
+                *status,
+                Some(StatusCode::UNAUTHORIZED) | Some(StatusCode::FORBIDDEN)
+            ),
+            _ => false,
+        }
+    }
+}
+
 impl From<serde_json::Error> for Error {
     fn from(err: serde_json::Error) -> Self {
         Self::Decode {
@@ -102,10 +155,10 @@ impl From<serde_json::Error> for Error {
 
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Self {
-        if err.is_request()
-            || err.is_redirect()
-            || err.is_timeout()
+        if err.is_timeout()
             || err.is_connect()
+            || err.is_request()
+            || err.is_redirect()
             || err.is_body()
             || err.is_status()
         {
@@ -113,6 +166,7 @@ impl From<reqwest::Error> for Error {
                 message: err.to_string(),
                 status: err.status(),
                 url: err.url().map_or("n/a".to_string(), |url| url.to_string()),
+                connection_failed: err.is_timeout() || err.is_connect(),
             }
         } else if err.is_decode() {
             Error::Decode {
@@ -168,6 +222,7 @@ pub(crate) fn is_request_error(value: Value, url: &String, status: &StatusCode)
             message: format!("{} - {}", err.error_type, err.message),
             status: Some(*status),
             url: url.to_string(),
+            connection_failed: false,
         });
     } else if let Ok(err) = serde_json::from_value::<CodeContextError>(value.clone()) {
         let mut details: Vec<String> = vec![];
@@ -181,12 +236,14 @@ pub(crate) fn is_request_error(value: Value, url: &String, status: &StatusCode)
                 message: format!("{} ({}) - {}", message, err.code, details.join(", ")),
                 status: Some(*status),
                 url: url.to_string(),
+                connection_failed: false,
             })
         } else {
             Err(Error::Request {
                 message: format!("({}) - {}", err.code, details.join(", ")),
                 status: Some(*status),
                 url: url.to_string(),
+                connection_failed: false,
             })
         };
     } else if let Ok(err) = serde_json::from_value::<ConstraintsError>(value) {
@@ -210,6 +267,7 @@ pub(crate) fn is_request_error(value: Value, url: &String, status: &StatusCode)
             message: format!("{}: {}", err.code, details.join(", ")),
             status: Some(*status),
             url: url.to_string(),
+            connection_failed: false,
         });
     }
     Ok(())
@@ -239,6 +297,7 @@ pub(crate) async fn check_request<T: DeserializeOwned>(url: String, resp: Respon
                 message: "The requested resource is not present".to_string(),
                 status: Some(resp.status()),
                 url,
+                connection_failed: false,
             })
         }
         429 => {
@@ -260,6 +319,7 @@ pub(crate) async fn check_request<T: DeserializeOwned>(url: String, resp: Respon
                 ),
                 status: Some(resp.status()),
                 url,
+                connection_failed: false,
             });
         }
         _ => resp.bytes().await?,
@@ -277,9 +337,18 @@ pub(crate) async fn check_request<T: DeserializeOwned>(url: String, resp: Respon
         url: url.clone(),
     })?;
     is_request_error(value.clone(), &url, &status)?;
-    serde_json::from_value::<T>(value).map_err(|e| Error::Decode {
-        message: format!("{} at {}:{}", e, e.line(), e.column()),
+    from_value_with_path::<T>(value).map_err(|message| Error::Decode {
+        message,
         content: raw.to_vec(),
         url,
     })
 }
+
+/// Deserialize a json value. If it fails, the returned error message contains the path to the
+/// field which caused the error (e.g. `data[3].episode_metadata.duration`).
+pub(crate) fn from_value_with_path<T: DeserializeOwned>(
+    value: Value,
+) -> std::result::Result<T, String> {
+    serde_path_to_error::deserialize(value)
+        .map_err(|e| format!("failed deserializing field `{}`: {}", e.path(), e.inner()))
+}
@@ -1,11 +1,11 @@
 //! Feeds like home feed or news feed.
 
 use crate::common::{Pagination, PaginationBulkResultMeta, V2BulkResult, V2TypeBulkResult};
-use crate::media::MediaType;
+use crate::media::{Media, MediaType};
 use crate::search::{BrowseOptions, BrowseSortType};
-use crate::{Crunchyroll, MediaCollection, Request, Series};
+use crate::{Crunchyroll, Episode, MediaCollection, Request, Result, Series};
 use chrono::{DateTime, Utc};
-use futures_util::FutureExt;
+use futures_util::{FutureExt, StreamExt, TryStreamExt};
 use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize};
 
@@ -92,6 +92,7 @@ pub struct SimilarFeed {
 }
 
 /// Items which can be shown on the home feed.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Serialize, Request)]
 pub enum HomeFeed {
     /// The feed at the top of the Crunchyroll website.
@@ -139,7 +140,7 @@ impl Default for HomeFeed {
 }
 
 impl<'de> Deserialize<'de> for HomeFeed {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
@@ -182,7 +183,7 @@ impl<'de> Deserialize<'de> for HomeFeed {
                             .ok_or_else(|| type_error("link", "string"))?
                             .to_string();
                         let query: Vec<(String, String)> =
-                            serde_urlencoded::from_str(link.split('?').last().unwrap())
+                            serde_urlencoded::from_str(link.split('?').next_back().unwrap())
                                 .map_err(|e| Error::custom(e.to_string()))?;
 
                         let mut browse_options = BrowseOptions::default();
@@ -285,6 +286,28 @@ impl<'de> Deserialize<'de> for HomeFeed {
     }
 }
 
+/// Media items of a [`HomeRail`].
+#[derive(Debug)]
+pub enum HomeRailItems {
+    /// Items which were fetched while building the rail.
+    Media(Vec<MediaCollection>),
+    /// Items which are fetched on demand as the rail may contain many of them.
+    Pagination(Pagination<MediaCollection>),
+    /// The rail has no media items (e.g. [`HomeFeed::Banner`]) or they must be fetched via a
+    /// dedicated method ([`HomeFeed::History`], [`HomeFeed::Watchlist`], [`HomeFeed::NewsFeed`]).
+    None,
+}
+
+/// A rail / row of the home screen, see [`Crunchyroll::home_rails`].
+#[derive(Debug)]
+pub struct HomeRail {
+    /// Title of the rail. Not all rails have one.
+    pub title: Option<String>,
+    /// The home feed item this rail was built from. Match on it to get the type of the rail.
+    pub feed: HomeFeed,
+    pub items: HomeRailItems,
+}
+
 /// Top news and latest news.
 pub struct NewsFeedResult {
     pub top_news: Pagination<NewsFeed>,
@@ -322,7 +345,7 @@ impl Crunchyroll {
                     let result = options
                         .executor
                         .get(endpoint)
-                        .query(&[("n", options.page_size), ("start", options.start)])
+                        .query(&options.offset_query())
                         .apply_locale_query()
                         .apply_preferred_audio_locale_query()
                         .request::<V2BulkResult<HomeFeed, PaginationBulkResultMeta>>()
@@ -337,6 +360,79 @@ impl Crunchyroll {
         )
     }
 
+    /// Returns the home screen as list of rails, like the official apps show it. This fetches the
+    /// whole [`Crunchyroll::home_feed`] and resolves the media of every item: items which contain
+    /// media (ids) directly are requested eagerly, rails which may contain many items
+    /// ([`HomeFeed::Recommendation`], [`HomeFeed::Browse`], [`HomeFeed::SimilarTo`]) are returned
+    /// as [`HomeRailItems::Pagination`]. Note that this makes a request for every series of a
+    /// [`HomeFeed::SeriesFeed`].
+    pub async fn home_rails(&self) -> Result<Vec<HomeRail>> {
+        let feeds: Vec<HomeFeed> = self.home_feed().try_collect().await?;
+
+        let mut rails = vec![];
+        for feed in feeds {
+            let (title, items) = match &feed {
+                HomeFeed::CarouselFeed(carousel) => (
+                    None,
+                    HomeRailItems::Media(
+                        carousel
+                            .iter()
+                            .filter_map(|item| item.panel.clone())
+                            .collect(),
+                    ),
+                ),
+                HomeFeed::Series(series) => (
+                    Some(series.title.clone()),
+                    HomeRailItems::Media(vec![MediaCollection::Series(series.clone())]),
+                ),
+                HomeFeed::Recommendation => {
+                    (None, HomeRailItems::Pagination(self.recommendations()))
+                }
+                HomeFeed::Banner(banner) => (Some(banner.title.clone()), HomeRailItems::None),
+                HomeFeed::SeriesFeed(series_feed) => {
+                    let series: Vec<MediaCollection> =
+                        futures_util::stream::iter(series_feed.ids.clone())
+                            .map(|id| async move { Series::from_id(self, id).await })
+                            .buffered(4)
+                            .map_ok(MediaCollection::Series)
+                            .try_collect()
+                            .await?;
+                    (
+                        Some(series_feed.title.clone()),
+                        HomeRailItems::Media(series),
+                    )
+                }
+                HomeFeed::Browse(options) => (
+                    None,
+                    HomeRailItems::Pagination(self.browse(options.clone())),
+                ),
+                HomeFeed::SimilarTo(similar_feed) => (
+                    Some(similar_feed.title.clone()),
+                    HomeRailItems::Pagination(
+                        self.similar_feed_series(&similar_feed.similar_id)
+                            .await?
+                            .similar(),
+                    ),
+                ),
+                _ => (None, HomeRailItems::None),
+            };
+            rails.push(HomeRail { title, feed, items })
+        }
+
+        Ok(rails)
+    }
+
+    /// [`SimilarFeed::similar_id`] can be the id of a series or episode.
+    async fn similar_feed_series(&self, id: &str) -> Result<Series> {
+        match Series::from_id(self, id).await {
+            Ok(series) => Ok(series),
+            Err(err) => match Episode::from_id(self, id).await {
+                Ok(episode) => episode.series().await,
+                Err(_) => Err(err),
+            },
+        }
+    }
+
     /// Returns Crunchyroll news.
     pub fn news_feed(&self) -> NewsFeedResult {
         NewsFeedResult {
@@ -413,7 +509,7 @@ impl Crunchyroll {
                     let result: V2BulkResult<MediaCollection, PaginationBulkResultMeta> = options
                         .executor
                         .get(endpoint)
-                        .query(&[("n", options.page_size), ("start", options.start)])
+                        .query(&options.offset_query())
                         .apply_locale_query()
                         .apply_preferred_audio_locale_query()
                         .request()
@@ -78,11 +78,99 @@ pub(crate) fn query_to_urlencoded<K: serde::Serialize, V: serde::Serialize>(
     Ok(q)
 }
 
-pub(crate) fn deserialize_millis_to_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+/// Deserialize a duration which can have different formats, depending on the endpoint: integers
+/// (and strings containing an integer) are interpreted as milliseconds, floats as seconds and other
+/// strings as ISO 8601 durations (e.g. `PT23M45S`). Negative durations are rejected.
+pub(crate) fn deserialize_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
 where
     D: Deserializer<'de>,
 {
-    Ok(Duration::try_milliseconds(i64::deserialize(deserializer)?).unwrap())
+    deserialize_duration_with_integer_unit(deserializer, 1)
+}
+
+/// Like [`deserialize_duration`], but integers (and strings containing an integer) are interpreted
+/// as seconds instead of milliseconds.
+pub(crate) fn deserialize_secs_to_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_duration_with_integer_unit(deserializer, 1000)
+}
+
+/// Deserialize a duration like described in [`deserialize_duration`], with integers being
+/// multiplied by `integer_unit_millis` to get milliseconds.
+fn deserialize_duration_with_integer_unit<'de, D>(
+    deserializer: D,
+    integer_unit_millis: i64,
+) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+
+    let millis = match &value {
+        Value::Number(number) => {
+            if let Some(integer) = number.as_i64() {
+                integer.checked_mul(integer_unit_millis)
+            } else {
+                number.as_f64().map(|secs| (secs * 1000.0) as i64)
+            }
+        }
+        Value::String(string) => {
+            if let Ok(integer) = string.parse::<i64>() {
+                integer.checked_mul(integer_unit_millis)
+            } else {
+                parse_iso8601_duration_millis(string)
+            }
+        }
+        _ => None,
+    };
+
+    millis
+        .filter(|millis| *millis >= 0)
+        .and_then(Duration::try_milliseconds)
+        .ok_or_else(|| SerdeError::custom(format!("invalid duration: '{value}'")))
+}
+
+/// Parse an ISO 8601 duration like `PT1H23M45.5S` to milliseconds. Only days, hours, minutes and
+/// seconds are supported as years, months and weeks have no fixed length / aren't used by
+/// Crunchyroll. At least one component must be given, so a bare `P` or `PT` is rejected.
+fn parse_iso8601_duration_millis(s: &str) -> Option<i64> {
+    let duration = s.strip_prefix('P')?;
+    let (date, time) = match duration.split_once('T') {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, time),
+        None => (duration, ""),
+    };
+    if date.is_empty() && time.is_empty() {
+        return None;
+    }
+
+    let mut millis = 0f64;
+    for (part, units) in [
+        (date, &[('D', 86_400_000f64)][..]),
+        (
+            time,
+            &[('H', 3_600_000f64), ('M', 60_000f64), ('S', 1000f64)][..],
+        ),
+    ] {
+        let mut rest = part;
+        for (unit, factor) in units {
+            if let Some((value, remaining)) = rest.split_once(*unit) {
+                // a sign isn't allowed in front of the single components
+                if !value.starts_with(|c: char| c.is_ascii_digit()) {
+                    return None;
+                }
+                millis += value.parse::<f64>().ok()? * factor;
+                rest = remaining;
+            }
+        }
+        if !rest.is_empty() {
+            return None;
+        }
+    }
+
+    Some(millis as i64)
 }
 
 pub(crate) fn serialize_duration_to_millis<S>(
@@ -137,6 +225,21 @@ where
     }
 }
 
+/// Some responses are empty arrays (or null) if they have no content but actually must be objects.
+pub(crate) fn deserialize_maybe_array_to_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + Default,
+{
+    let value: Value = Deserialize::deserialize(deserializer)?;
+
+    if value.is_array() || value.is_null() {
+        Ok(T::default())
+    } else {
+        serde_json::from_value(value).map_err(|e| serde::de::Error::custom(e.to_string()))
+    }
+}
+
 /// Deserializes a empty string (`""`) to `None`.
 pub(crate) fn deserialize_empty_pre_string_to_none<'de, D, T>(
     deserializer: D,
@@ -169,6 +272,8 @@ pub(crate) fn deserialize_thumbnail_image<'de, D: Deserializer<'de>>(
     }
 }
 
+/// Extract the stream id out of a streams link. The link can be either a relative path or an
+/// absolute url, as only the last path segment is relevant.
 pub(crate) fn deserialize_streams_link<'de, D: Deserializer<'de>>(
     deserializer: D,
 ) -> Result<String, D::Error> {
@@ -177,11 +282,28 @@ pub(crate) fn deserialize_streams_link<'de, D: Deserializer<'de>>(
     Ok(as_string
         .trim_end_matches("/streams")
         .split('/')
-        .last()
+        .next_back()
         .ok_or_else(|| serde::de::Error::custom("cannot extract stream id"))?
         .to_string())
 }
 
+/// Newer endpoints wrap media in a `{ "panel": { ... } }` object, sometimes next to additional
+/// fields which belong to the wrapper (like `playhead` or `fully_watched`). If the given map is
+/// such a wrapper (it has a `panel` object but no `id` of its own), the panel is returned.
+pub(crate) fn unwrap_panel(mut map: Map<String, Value>) -> Map<String, Value> {
+    if map.contains_key("id") {
+        return map;
+    }
+    match map.remove("panel") {
+        Some(Value::Object(panel)) => panel,
+        Some(panel) => {
+            map.insert("panel".to_string(), panel);
+            map
+        }
+        None => map,
+    }
+}
+
 pub(crate) fn deserialize_panel<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
     D: Deserializer<'de>,
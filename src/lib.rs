@@ -121,14 +121,21 @@
 //! - **dash-stream**: Enables processing of [DASH] video streams.
 //! - **parse** *(enabled by default)*: Enables url parsing.
 //! - **tower**: Enables the usage of a [tower](https://docs.rs/tower) compatible middleware.
+//! - **rustls-tls** *(enabled by default)*: Uses [`rustls`](https://docs.rs/rustls) as tls backend.
+//! - **native-tls**: Uses the platform native tls backend (e.g. openssl on Linux), for environments
+//!   where rustls can't be used. Only has an effect if **rustls-tls** is disabled.
 //! - **experimental-stabilizations**: Provides some functions to maybe fix broken api results. See
 //!   [Bugs](#bugs) for more information.
+//! - **testing**: Provides media fixtures ([`testing`]) to test code which consumes media without
+//!   network access.
 //!
 //! # Implementation
 //! To ensure at least all existing parts of the library are working as expected, a special feature
 //! only for testing is implemented. When running tests with the `__test_strict` feature, it ensures
 //! that no fields were added or removed from an api response, otherwise the associated test will
-//! fail.
+//! fail. Without this feature (which should never be enabled outside of this crate's tests),
+//! unknown fields in api responses are ignored, so Crunchyroll adding new fields doesn't break
+//! deserialization.
 //!
 //! [HLS]: https://en.wikipedia.org/wiki/HTTP_Live_Streaming
 //! [DASH]: https://en.wikipedia.org/wiki/Dynamic_Adaptive_Streaming_over_HTTP
@@ -149,6 +156,9 @@ pub mod media;
 pub mod parse;
 pub mod rating;
 pub mod search;
+#[cfg(any(test, feature = "testing"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 
 // internal
 mod internal;
@@ -166,7 +176,7 @@ pub use media::{
     Concert, Episode, MediaCollection, Movie, MovieListing, MusicVideo, Season, Series,
 };
 #[cfg(feature = "parse")]
-pub use parse::{parse_url, UrlType};
+pub use parse::{build_url, parse_url, UrlType};
 
 #[cfg(feature = "__test_strict")]
 use internal::strict::StrictValue;
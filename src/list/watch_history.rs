@@ -39,7 +39,7 @@ impl Crunchyroll {
                     let result: V2BulkResult<WatchHistoryEntry, PaginationBulkResultMeta> = options
                         .executor
                         .get(endpoint)
-                        .query(&[("page", options.page), ("page_size", options.page_size)])
+                        .query(&options.page_query())
                         .apply_locale_query()
                         .request()
                         .await?;
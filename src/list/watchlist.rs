@@ -1,11 +1,19 @@
-use crate::common::V2BulkResult;
+use crate::common::{
+    offset_query, Pagination, PaginationBulkResultMeta, PaginationData, PaginationNextType,
+    V2BulkResult,
+};
 use crate::error::Error;
+use crate::media::RelatedMedia;
 use crate::{
-    enum_values, options, Crunchyroll, EmptyJsonProxy, Executor, MediaCollection, Request, Result,
+    enum_values, options, Crunchyroll, EmptyJsonProxy, Episode, Executor, MediaCollection, Request,
+    Result,
 };
 use chrono::{DateTime, Utc};
+use futures_util::FutureExt;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::HashMap;
+use std::io::Write;
 use std::sync::Arc;
 
 /// A item in your watchlist.
@@ -116,6 +124,15 @@ enum_values! {
     }
 }
 
+/// Format in which [`Crunchyroll::export_watchlist`] writes the watchlist.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExportFormat {
+    /// Comma separated values with a header line.
+    Csv,
+    /// One json object per line.
+    JsonLines,
+}
+
 options! {
     /// Options how to query the watchlist.
     WatchlistOptions;
@@ -155,6 +172,201 @@ impl Crunchyroll {
             .await?
             .data)
     }
+
+    /// Exports your watchlist to the given writer. The watchlist is requested page by page and
+    /// every page is written before the next one is requested, so the whole watchlist is never held
+    /// in memory. Every entry has the columns / fields `id`, `title`, `type` (`series` or
+    /// `movie_listing`), `progress` (`never_watched`, `in_progress` or `fully_watched`) and
+    /// `date_added` (RFC 3339, empty if unknown). Every entry is written with a single
+    /// [`Write::write_all`] call, if writing fails, [`Error::Input`] is returned and all entries
+    /// before the failing one are written completely.
+    pub async fn export_watchlist(&self, w: &mut impl Write, format: ExportFormat) -> Result<()> {
+        let page_size = 100;
+        let account_id = self.executor.details.account_id.clone()?;
+        let write = |w: &mut dyn Write, line: String| {
+            w.write_all(line.as_bytes()).map_err(|e| Error::Input {
+                message: e.to_string(),
+            })
+        };
+
+        if format == ExportFormat::Csv {
+            write(w, "id,title,type,progress,date_added\n".to_string())?;
+        }
+
+        let mut start = 0;
+        loop {
+            let endpoint = format!(
+                "https://www.crunchyroll.com/content/v2/discover/{}/watchlist",
+                account_id
+            );
+            let page: V2BulkResult<WatchlistEntry> = self
+                .executor
+                .get(endpoint)
+                .query(&offset_query(page_size, start))
+                .query(&[("order", WatchlistOrder::Newest.to_string())])
+                .apply_locale_query()
+                .request()
+                .await?;
+            if page.data.is_empty() {
+                break;
+            }
+
+            // the entries of the discover endpoint don't contain the date they were added, so it's
+            // requested for the whole page at once
+            let ids: Vec<&str> = page
+                .data
+                .iter()
+                .map(|entry| entry.panel.as_media_base().id)
+                .collect();
+            let endpoint = format!(
+                "https://www.crunchyroll.com/content/v2/{}/watchlist",
+                account_id
+            );
+            let dates_added: HashMap<String, DateTime<Utc>> = self
+                .executor
+                .get(endpoint)
+                .query(&[("content_ids", ids.join(","))])
+                .apply_locale_query()
+                .request::<V2BulkResult<SimpleWatchlistEntry>>()
+                .await?
+                .data
+                .into_iter()
+                .map(|entry| (entry.id, entry.date_added))
+                .collect();
+
+            for entry in &page.data {
+                let base = entry.panel.as_media_base();
+                let media_type = match entry.panel {
+                    MediaCollection::MovieListing(_) => "movie_listing",
+                    _ => "series",
+                };
+                let progress = if entry.fully_watched {
+                    "fully_watched"
+                } else if entry.never_watched {
+                    "never_watched"
+                } else {
+                    "in_progress"
+                };
+                let date_added = dates_added
+                    .get(base.id)
+                    .map(|date| date.to_rfc3339())
+                    .unwrap_or_default();
+
+                let line = match format {
+                    ExportFormat::Csv => format!(
+                        "{},{},{},{},{}\n",
+                        csv_field(base.id),
+                        csv_field(base.title),
+                        media_type,
+                        progress,
+                        date_added
+                    ),
+                    ExportFormat::JsonLines => format!(
+                        "{}\n",
+                        json!({
+                            "id": base.id,
+                            "title": base.title,
+                            "type": media_type,
+                            "progress": progress,
+                            "date_added": date_added
+                        })
+                    ),
+                };
+                write(w, line)?;
+            }
+
+            start += page.data.len() as u32;
+            if start >= page.total {
+                break;
+            }
+        }
+
+        w.flush().map_err(|e| Error::Input {
+            message: e.to_string(),
+        })
+    }
+
+    /// Returns newly released episodes of series on your watchlist, like the "new episodes" tab in
+    /// the apps. Crunchyroll has no dedicated endpoint for this, so the watchlist is requested page
+    /// by page and for every series which has new content ([`WatchlistEntry::new`]) the next
+    /// episode you haven't watched yet is returned. A page may therefore contain fewer episodes
+    /// than the page size and every new series requires an extra request. If a preferred audio
+    /// locale is set, the episodes are returned in this audio (if available).
+    pub fn new_episodes(&self) -> Pagination<Episode> {
+        Pagination::new(
+            |options| {
+                async move {
+                    let endpoint = format!(
+                        "https://www.crunchyroll.com/content/v2/discover/{}/watchlist",
+                        options.executor.details.account_id.clone()?
+                    );
+                    // the start can't be derived from the number of returned episodes as entries
+                    // without new content are skipped
+                    let start = (options.page - 1) * options.page_size;
+                    let result: V2BulkResult<WatchlistEntry, PaginationBulkResultMeta> = options
+                        .executor
+                        .get(endpoint)
+                        .query(&offset_query(options.page_size, start))
+                        .query(&[("order", WatchlistOrder::Newest.to_string())])
+                        .apply_locale_query()
+                        .request()
+                        .await?;
+
+                    let mut episodes = vec![];
+                    for entry in &result.data {
+                        let MediaCollection::Series(series) = &entry.panel else {
+                            continue;
+                        };
+                        if !entry.new {
+                            continue;
+                        }
+                        let endpoint = format!(
+                            "https://www.crunchyroll.com/content/v2/discover/up_next/{}",
+                            series.id
+                        );
+                        let up_next: serde_json::Value = options
+                            .executor
+                            .get(endpoint)
+                            .apply_locale_query()
+                            .apply_preferred_audio_locale_query()
+                            .request()
+                            .await?;
+                        if !matches!(up_next.as_object(), Some(map) if !map.is_empty()) {
+                            continue;
+                        }
+                        let mut up_next: V2BulkResult<RelatedMedia<Episode>> =
+                            serde_json::from_value(up_next)?;
+                        if !up_next.data.is_empty() && !up_next.data[0].fully_watched {
+                            let mut episode = up_next.data.remove(0).media;
+                            episode.__set_executor(options.executor.clone()).await;
+                            episodes.push(episode.into_preferred_audio_version().await?)
+                        }
+                    }
+
+                    Ok(PaginationData {
+                        data: episodes,
+                        next_type: PaginationNextType::NextPage(
+                            start + (result.data.len() as u32) < result.total
+                                && !result.data.is_empty(),
+                        ),
+                    })
+                }
+                .boxed()
+            },
+            self.executor.clone(),
+            None,
+            None,
+        )
+    }
+}
+
+/// Quote a csv field if it contains characters which have a special meaning in csv.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
 }
 
 macro_rules! add_to_watchlist {
@@ -1,10 +1,18 @@
 use crate::common::Image;
 use crate::crunchyroll::Executor;
+use crate::error::Error;
 use crate::media::util::request_media;
-use crate::media::Media;
+#[cfg(feature = "hls-stream")]
+use crate::media::{CancellationToken, StreamPreferences, StreamQuality};
+use crate::media::{Media, MediaId, SearchMetadata, Subtitle};
+#[cfg(any(feature = "hls-stream", feature = "dash-stream"))]
+use crate::media::{StreamProtocol, StreamSummary};
 use crate::{Crunchyroll, Locale, Result, Season, Series};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+#[cfg(feature = "hls-stream")]
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 #[allow(dead_code)]
@@ -36,12 +44,23 @@ pub(crate) struct EpisodeVersion {
 pub struct Episode {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
+    /// Cache for [`Self::fresh_stream`], with the time the stream was requested.
+    #[serde(skip)]
+    pub(crate) stream_cache: crate::media::StreamCache,
+    /// Names of the optional date fields which were present (and not null) when this struct was
+    /// deserialized, see the `*_opt` date methods.
+    #[serde(skip)]
+    pub(crate) present_dates: Vec<&'static str>,
 
     pub id: String,
     #[serde(alias = "streams_link")]
     #[serde(deserialize_with = "crate::internal::serde::deserialize_streams_link")]
     pub stream_id: String,
     pub channel_id: String,
+    /// Id of this media on the legacy Crunchyroll website (before the 2022 website overhaul),
+    /// prefixed with a type identifier (e.g. `EPI.907016`). Only set for media which already
+    /// existed on the legacy website.
+    pub external_id: Option<String>,
     pub identifier: String,
 
     pub slug: String,
@@ -82,13 +101,15 @@ pub struct Episode {
 
     pub season_number: u32,
 
+    /// Might be empty in some api results, use [`Episode::resolved_audio_locale`] to get a
+    /// reliable audio locale.
     pub audio_locale: Locale,
     /// Only populated if [`Episode`] got generated via [`Season::episodes`].
     pub recent_audio_locale: Option<Locale>,
     pub subtitle_locales: Vec<Locale>,
 
     #[serde(alias = "duration_ms")]
-    #[serde(deserialize_with = "crate::internal::serde::deserialize_millis_to_duration")]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_duration")]
     #[serde(serialize_with = "crate::internal::serde::serialize_duration_to_millis")]
     #[default(Duration::try_milliseconds(0).unwrap())]
     pub duration: Duration,
@@ -110,6 +131,11 @@ pub struct Episode {
     #[serde(deserialize_with = "crate::internal::serde::deserialize_thumbnail_image")]
     pub images: Vec<Image>,
 
+    /// Ranking information, only populated if the episode was returned by a search or browse
+    /// request.
+    #[serde(default)]
+    pub search_metadata: Option<SearchMetadata>,
+
     pub is_dubbed: bool,
     pub is_subbed: bool,
 
@@ -131,11 +157,14 @@ pub struct Episode {
     #[serde(skip_serializing)]
     pub(crate) versions: Option<Vec<EpisodeVersion>>,
 
+    #[serde(rename = "__links__")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub(crate) links: crate::media::MediaLinks,
+
     #[cfg(feature = "__test_strict")]
     media_type: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
-    external_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
     linked_resource_key: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
     new: Option<crate::StrictValue>,
@@ -144,8 +173,6 @@ pub struct Episode {
     #[cfg(feature = "__test_strict")]
     promo_description: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
-    search_metadata: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
     #[serde(rename = "type")]
     _type: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
@@ -168,6 +195,19 @@ pub struct Episode {
     recent_variant: Option<crate::StrictValue>,
 }
 
+/// Result of [`Episode::archive_to`]. Contains the path of every saved file or the error which
+/// occurred while saving it.
+#[cfg(feature = "hls-stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hls-stream")))]
+#[derive(Debug)]
+pub struct ArchiveResult {
+    pub video: Result<PathBuf>,
+    /// Empty if no subtitles were saved, e.g. because only a hardsubbed variant is available.
+    pub subtitles: Vec<(Locale, Result<PathBuf>)>,
+    /// [`None`] if the episode has no thumbnail.
+    pub thumbnail: Option<Result<PathBuf>>,
+}
+
 impl Episode {
     /// Returns the series the episode belongs to.
     pub async fn series(&self) -> Result<Series> {
@@ -190,17 +230,311 @@ impl Episode {
             .await?
             .remove(0))
     }
+
+    /// Returns the parts this episode consists of. Crunchyroll never splits episodes into multiple
+    /// parts (the stream of an episode always covers the whole episode), so this always returns a
+    /// one-element vec containing the episode itself. Exists to be used interchangeably with
+    /// [`crate::Movie::parts`].
+    pub fn parts(&self) -> Vec<Episode> {
+        vec![self.clone()]
+    }
+
+    /// Check if this is the last episode of its season, i.e. no episode in the season has a higher
+    /// [`Episode::sequence_number`]. If the season is still airing ([`Season::is_complete`] is
+    /// `false`) this is only provisional and only true until the next episode is released.
+    pub async fn is_last_in_season(&self) -> Result<bool> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/cms/seasons/{}/episodes",
+            self.season_id
+        );
+        let episodes: Vec<Episode> = request_media(self.executor.clone(), endpoint).await?;
+        Ok(episodes
+            .iter()
+            .all(|episode| episode.sequence_number <= self.sequence_number))
+    }
+
+    /// Returns the version of this episode which has the preferred audio locale
+    /// ([`crate::crunchyroll::CrunchyrollBuilder::preferred_audio_locale`]). The episode itself is
+    /// returned if no preferred audio locale is set or no version with it exists. Crunchyroll
+    /// ignores the preferred audio locale on some endpoints (e.g. up next), so their results must
+    /// be resolved manually.
+    pub(crate) async fn into_preferred_audio_version(mut self) -> Result<Episode> {
+        let Some(preferred) = self.executor.details.preferred_audio_locale.clone() else {
+            return Ok(self);
+        };
+        if self.audio_locale == preferred {
+            return Ok(self);
+        }
+        Ok(self
+            .version(vec![preferred])
+            .await?
+            .into_iter()
+            .next()
+            .unwrap_or(self))
+    }
+
+    /// Returns the audio locale of this episode. [`Episode::audio_locale`] is empty in some api
+    /// results, in this case the locale is looked up in the versions of the episode (which requires
+    /// the episode to be re-requested if it doesn't contain its versions). If this doesn't help
+    /// either, the audio locale of the parent season is used.
+    pub async fn resolved_audio_locale(&self) -> Result<Locale> {
+        if self.audio_locale != Locale::default() {
+            return Ok(self.audio_locale.clone());
+        }
+
+        let versions = if self
+            .versions
+            .as_ref()
+            .is_some_and(|versions| versions.iter().any(|v| v.id == self.id))
+        {
+            self.versions.clone().unwrap()
+        } else {
+            let re_requested =
+                Episode::from_id(&Crunchyroll::from_executor(self.executor.clone()), &self.id)
+                    .await?;
+            if re_requested.audio_locale != Locale::default() {
+                return Ok(re_requested.audio_locale);
+            }
+            re_requested.versions.unwrap_or_default()
+        };
+        if let Some(version) = versions
+            .into_iter()
+            .find(|v| v.id == self.id && v.audio_locale != Locale::default())
+        {
+            return Ok(version.audio_locale);
+        }
+
+        let season = self.season().await?;
+        if let [audio_locale] = season.audio_locales.as_slice() {
+            return Ok(audio_locale.clone());
+        }
+        if let Some(version) = season
+            .versions
+            .unwrap_or_default()
+            .into_iter()
+            .find(|v| v.id == season.id && v.audio_locale != Locale::default())
+        {
+            return Ok(version.audio_locale);
+        }
+
+        Err(Error::Internal {
+            message: format!("could not resolve audio locale of episode '{}'", self.id),
+        })
+    }
+
+    /// Returns the subtitles of this episode and all its versions (dubs), one per locale. A single
+    /// [`Episode::stream`] only contains the subtitles of one version, so the stream of every
+    /// version is requested and the subtitles are merged. If multiple versions have subtitles with
+    /// the same locale, the one of this episode is preferred, followed by the order of
+    /// [`Episode::versions`].
+    pub async fn all_subtitles(&self) -> Result<HashMap<Locale, Subtitle>> {
+        let mut subtitles = self.stream().await?.subtitles;
+        for version in self.clone().versions().await? {
+            for (locale, subtitle) in version.stream().await?.subtitles {
+                subtitles.entry(locale).or_insert(subtitle);
+            }
+        }
+        Ok(subtitles)
+    }
+
+    /// Returns the region codes (e.g. `DE` or `US`) of all regions in which the episode is
+    /// available. [`Episode::eligible_region`] can contain multiple regions, separated by commas or
+    /// whitespaces; this splits them.
+    pub fn eligible_regions(&self) -> Vec<String> {
+        self.eligible_region
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|region| !region.is_empty())
+            .map(|region| region.to_string())
+            .collect()
+    }
+
+    /// Returns all qualities in which the version of this episode with the given audio locale is
+    /// available without drm, sorted from highest to lowest resolution. The qualities are taken
+    /// from the non-drm [HLS](https://en.wikipedia.org/wiki/HTTP_Live_Streaming) stream without
+    /// hardsub. If the episode has no version with the audio locale, [`Error::Input`] is returned;
+    /// if no non-drm stream is available, the result is empty.
+    #[cfg(feature = "hls-stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hls-stream")))]
+    pub async fn quality_options(&self, audio: &Locale) -> Result<Vec<StreamQuality>> {
+        let episode = if &self.audio_locale == audio {
+            self.clone()
+        } else {
+            self.clone()
+                .version(vec![audio.clone()])
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| Error::Input {
+                    message: format!("episode has no version with audio locale '{audio}'"),
+                })?
+        };
+
+        let stream = episode.stream().await?;
+        // the same variants which are used by `hls_streaming_data` if no hardsub is requested
+        let has_non_drm_hls = [Locale::Custom("".into()), Locale::Custom(":".into())]
+            .iter()
+            .find_map(|locale| stream.variants.get(locale))
+            .is_some_and(|variants| variants.adaptive_hls.is_some());
+        if !has_non_drm_hls {
+            return Ok(vec![]);
+        }
+        let variants = stream.hls_streaming_data(None).await?;
+
+        let mut qualities: Vec<StreamQuality> = variants.iter().map(StreamQuality::from).collect();
+        qualities.sort_by_key(|quality| std::cmp::Reverse(quality.resolution.width));
+        Ok(qualities)
+    }
+
+    /// Returns which audios, subtitles, hardsubs and qualities this episode is available in, e.g.
+    /// to let the user choose what to download. Requires one request for the stream and the
+    /// qualities plus one for the versions if this episode doesn't contain its versions already
+    /// (see [`Episode::available_versions`]). The qualities are preferably the
+    /// [HLS](https://en.wikipedia.org/wiki/HTTP_Live_Streaming) ones.
+    #[cfg(any(feature = "hls-stream", feature = "dash-stream"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "hls-stream", feature = "dash-stream"))))]
+    pub async fn stream_summary(&self) -> Result<StreamSummary> {
+        let stream = self.stream().await?;
+
+        let mut audio_locales = vec![stream.audio_locale.clone()];
+        audio_locales.extend(self.clone().available_versions().await?);
+        audio_locales.retain(|locale| locale != &Locale::default());
+        crate::media::anime::util::real_dedup_vec(&mut audio_locales);
+
+        let mut subtitle_locales: Vec<Locale> = stream.subtitles.keys().cloned().collect();
+        subtitle_locales.sort_by_key(|locale| locale.to_string());
+        let mut hardsub_locales: Vec<Locale> = stream
+            .streaming_hardsub_locales()
+            .into_iter()
+            .filter(|locale| !matches!(locale, Locale::Custom(raw) if raw.is_empty() || raw == ":"))
+            .collect();
+        hardsub_locales.sort_by_key(|locale| locale.to_string());
+
+        let drm_only = stream
+            .variants
+            .values()
+            .all(|variants| variants.is_drm_only());
+        let qualities = stream.quality_ladder(None, StreamProtocol::Hls).await?;
+
+        Ok(StreamSummary {
+            audio_locales,
+            subtitle_locales,
+            hardsub_locales,
+            drm_only,
+            qualities,
+        })
+    }
+
+    /// Save this episode for offline viewing into the given directory: the best (highest
+    /// resolution) [HLS](https://en.wikipedia.org/wiki/HTTP_Live_Streaming) stream variant,
+    /// all subtitles (if [`StreamPreferences::soft_subs`] is enabled and a variant without hardsub
+    /// exists) and the thumbnail. The files are named after the episode id, e.g. `GRDKJZ81Y.ts`,
+    /// `GRDKJZ81Y.en-US.ass` and `GRDKJZ81Y.jpg`. If [`StreamPreferences::audio`] is set and a
+    /// version with this audio exists, the version is saved instead. Video, subtitles and thumbnail
+    /// are downloaded concurrently and independently of each other, so a failing component doesn't
+    /// abort the others; its error is returned as part of [`ArchiveResult`]. Only resolving the
+    /// stream and creating the directory are failing the whole function. If `cancellation` gets
+    /// cancelled, every component which isn't finished yet fails with [`Error::Cancelled`], the
+    /// video download can be resumed by calling this function again (see
+    /// [`crate::media::VariantData::download_to`]).
+    #[cfg(feature = "hls-stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hls-stream")))]
+    pub async fn archive_to<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        preferences: StreamPreferences,
+        cancellation: Option<CancellationToken>,
+    ) -> Result<ArchiveResult> {
+        let dir = dir.as_ref();
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| Error::Input {
+                message: e.to_string(),
+            })?;
+
+        let mut episode = self.clone();
+        if let Some(audio) = preferences.audio.clone() {
+            if episode.audio_locale != audio {
+                if let Some(version) = episode.version(vec![audio]).await?.into_iter().next() {
+                    episode = version
+                }
+            }
+        }
+        let stream = episode.stream().await?;
+        let hardsub = stream.preferred_hardsub(&preferences);
+        let soft_subs = preferences.soft_subs && hardsub.is_none();
+
+        let video_path = dir.join(format!("{}.ts", episode.id));
+        let video = async {
+            let mut variants = stream.hls_streaming_data(hardsub).await?;
+            variants.sort_by_key(|v| v.resolution.width);
+            let variant = variants.pop().ok_or(Error::Input {
+                message: "no stream available".to_string(),
+            })?;
+            variant
+                .download_to(&video_path, cancellation.clone())
+                .await?;
+            Ok(video_path.clone())
+        };
+
+        let subtitles =
+            futures_util::future::join_all(stream.subtitles.values().filter(|_| soft_subs).map(
+                |subtitle| async {
+                    let path = dir.join(subtitle.suggested_filename(&episode.id));
+                    let result = async {
+                        CancellationToken::check(cancellation.as_ref())?;
+                        let mut buf = vec![];
+                        subtitle.clone().write_to(&mut buf).await?;
+                        tokio::fs::write(&path, buf)
+                            .await
+                            .map_err(|e| Error::Input {
+                                message: e.to_string(),
+                            })?;
+                        Ok(path)
+                    }
+                    .await;
+                    (subtitle.locale.clone(), result)
+                },
+            ));
+
+        let thumbnail = async {
+            let image = episode.images.iter().max_by_key(|image| image.width)?;
+            let extension = Path::new(image.source.split('?').next().unwrap_or_default())
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_string())
+                .unwrap_or("jpg".to_string());
+            let path = dir.join(format!("{}.{}", episode.id, extension));
+            let result = async {
+                CancellationToken::check(cancellation.as_ref())?;
+                let raw = self.executor.get(&image.source).request_asset().await?;
+                tokio::fs::write(&path, raw)
+                    .await
+                    .map_err(|e| Error::Input {
+                        message: e.to_string(),
+                    })?;
+                Ok(path)
+            }
+            .await;
+            Some(result)
+        };
+
+        let (video, subtitles, thumbnail) =
+            futures_util::future::join3(video, subtitles, thumbnail).await;
+        Ok(ArchiveResult {
+            video,
+            subtitles,
+            thumbnail,
+        })
+    }
 }
 
 #[async_trait::async_trait]
 impl Media for Episode {
-    async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
+    async fn from_id(crunchyroll: &Crunchyroll, id: impl Into<MediaId> + Send) -> Result<Self> {
+        let id: MediaId = id.into();
+        id.validate()?;
         Ok(request_media(
             crunchyroll.executor.clone(),
-            format!(
-                "https://www.crunchyroll.com/content/v2/cms/episodes/{}",
-                id.as_ref()
-            ),
+            format!("https://www.crunchyroll.com/content/v2/cms/episodes/{}", id),
         )
         .await?
         .remove(0))
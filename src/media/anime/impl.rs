@@ -1,10 +1,16 @@
 use crate::common::{PaginationBulkResultMeta, Request};
 use crate::media::Media;
-use crate::{Episode, MediaCollection, Movie, MovieListing, Result, Season, Series};
-use chrono::{DateTime, Utc};
+use crate::{Crunchyroll, Episode, MediaCollection, Movie, MovieListing, Result, Season, Series};
+use chrono::{DateTime, Duration, Utc};
 use serde::de::{DeserializeOwned, Error, IntoDeserializer};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
+
+/// How long [`Episode::fresh_stream`] / [`Movie::fresh_stream`] cache a stream whose urls have no
+/// parsable expiry. Signed stream urls are usually valid for a few hours at least, so this is
+/// conservative enough to not hand out expired urls.
+const FRESH_STREAM_FALLBACK_TTL_MINUTES: i64 = 10;
 
 /// Information about the intro of an [`Episode`] or [`Movie`].
 #[allow(dead_code)]
@@ -18,7 +24,9 @@ struct VideoIntroResult {
     start_time: f64,
     #[serde(rename = "endTime")]
     end_time: f64,
-    duration: f64,
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_secs_to_duration")]
+    #[default(Duration::zero())]
+    duration: Duration,
 
     /// Id of the next episode.
     #[serde(rename = "comparedWith")]
@@ -91,7 +99,7 @@ impl<'de> Deserialize<'de> for SkipEvents {
             let Some(obj) = as_map.get(object) else {
                 continue;
             };
-            if obj.as_object().map_or(false, |o| o.is_empty())
+            if obj.as_object().is_some_and(|o| o.is_empty())
                 // crunchyroll sometimes has a skip events, but it's lacking start or end times.
                 // this is just abstracted away since an event without a start or end doesn't make
                 // sense to be wrapped in e.g. an Option
@@ -129,6 +137,29 @@ pub struct RelatedMedia<T: Request + DeserializeOwned> {
     pub shortcut: Option<bool>,
 }
 
+/// Similar media of a [`Series`] or [`MovieListing`], including the meta information Crunchyroll
+/// returns alongside. Returned by [`Series::similar_with_meta`] / [`MovieListing::similar_with_meta`].
+#[derive(Clone, Debug, Serialize)]
+pub struct SimilarResult {
+    /// Similar media, in the order Crunchyroll ranked them.
+    pub items: Vec<MediaCollection>,
+    /// Total number of similar media which are available.
+    pub total: u32,
+    pub meta: SimilarMeta,
+}
+
+/// Meta information of [`SimilarResult`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SimilarMeta {
+    pub prev_page: Option<String>,
+    pub next_page: Option<String>,
+    /// All other meta fields Crunchyroll returns. They are not documented and may contain
+    /// additional ranking context.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, Value>,
+}
+
 /// Information about the playhead of an [`Episode`] or [`Movie`].
 #[allow(dead_code)]
 #[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault, Request)]
@@ -146,6 +177,54 @@ pub struct PlayheadInformation {
     pub last_modified: DateTime<Utc>,
 }
 
+impl PlayheadInformation {
+    /// The playhead as [`Duration`].
+    pub fn playhead_duration(&self) -> Duration {
+        Duration::try_seconds(self.playhead as i64).unwrap()
+    }
+
+    /// Convert a [`Duration`] to a playhead value as it's used by the api (whole seconds).
+    /// Sub-second parts are floored, negative durations are treated as zero and durations which
+    /// exceed the maximum playhead value are capped to it.
+    pub fn duration_to_playhead(duration: Duration) -> u32 {
+        duration.num_seconds().clamp(0, u32::MAX as i64) as u32
+    }
+}
+
+impl Crunchyroll {
+    /// Get the playhead information of multiple episodes / movies at once. The ids are requested
+    /// in chunks of 50, so only one request per 50 ids is made instead of one per id (like it would
+    /// be with [`Episode::playhead`] / [`Movie::playhead`]). Every given id is contained in the
+    /// result, if an id has no playhead its value is [`None`].
+    pub async fn playheads_for(
+        &self,
+        content_ids: &[String],
+    ) -> Result<HashMap<String, Option<PlayheadInformation>>> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/{}/playheads",
+            self.executor.details.account_id.clone()?
+        );
+
+        let mut result: HashMap<String, Option<PlayheadInformation>> =
+            content_ids.iter().map(|id| (id.clone(), None)).collect();
+        for chunk in content_ids.chunks(50) {
+            let playheads = self
+                .executor
+                .get(&endpoint)
+                .query(&[("content_ids", chunk.join(","))])
+                .apply_locale_query()
+                .request::<crate::common::V2BulkResult<PlayheadInformation>>()
+                .await?
+                .data;
+            for playhead in playheads {
+                result.insert(playhead.content_id.clone(), Some(playhead));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
 macro_rules! impl_manual_media_deserialize {
     ($($media:ident = $metadata:literal)*) => {
         $(
@@ -154,7 +233,9 @@ macro_rules! impl_manual_media_deserialize {
                 where
                     D: Deserializer<'de>,
                 {
-                    let mut as_map = serde_json::Map::deserialize(deserializer)?;
+                    let mut as_map = $crate::internal::serde::unwrap_panel(
+                        serde_json::Map::deserialize(deserializer)?,
+                    );
 
                     if let Some(mut metadata) = as_map.remove($metadata) {
                         if let Some(object) = metadata.as_object_mut() {
@@ -164,12 +245,27 @@ macro_rules! impl_manual_media_deserialize {
                         }
                     }
 
-                    $media::deserialize(
+                    // null dates are removed so that they fall back to the default like missing
+                    // ones
+                    let mut present_dates = vec![];
+                    for date in <$media as OptionalDates>::OPTIONAL_DATES {
+                        match as_map.get(*date) {
+                            Some(Value::Null) => {
+                                as_map.remove(*date);
+                            }
+                            Some(_) => present_dates.push(*date),
+                            None => (),
+                        }
+                    }
+
+                    let mut media = $media::deserialize(
                         serde_json::to_value(as_map)
                             .map_err(|e| Error::custom(e.to_string()))?
                             .into_deserializer(),
                     )
-                    .map_err(|e| Error::custom(e.to_string()))
+                    .map_err(|e| Error::custom(e.to_string()))?;
+                    media.set_present_dates(present_dates);
+                    Ok(media)
                 }
             }
         )*
@@ -240,6 +336,162 @@ media_eq! {
     Series Season Episode MovieListing Movie
 }
 
+macro_rules! media_links {
+    ($($media:ident)*) => {
+        $(
+            impl $media {
+                /// Links to resources which are related to this media, like its streams or its
+                /// parent / child media. Might be empty if the endpoint which returned this media
+                /// doesn't provide any links.
+                pub fn links(&self) -> &$crate::media::MediaLinks {
+                    &self.links
+                }
+            }
+        )*
+    }
+}
+
+media_links! {
+    Series Season Episode MovieListing Movie
+}
+
+macro_rules! media_channel {
+    ($($media:ident)*) => {
+        $(
+            impl $media {
+                /// The channel this media is published on, parsed from the `channel_id` field.
+                pub fn channel(&self) -> $crate::media::Channel {
+                    $crate::media::Channel::from(self.channel_id.clone())
+                }
+            }
+        )*
+    }
+}
+
+media_channel! {
+    Series Season Episode MovieListing Movie
+}
+
+/// Date fields which are optional in api responses. Missing dates are defaulting to
+/// [`std::time::SystemTime::UNIX_EPOCH`] in the fields themselves, so it's recorded while
+/// deserializing which of them were actually present.
+trait OptionalDates {
+    const OPTIONAL_DATES: &'static [&'static str] = &[];
+
+    fn set_present_dates(&mut self, _present_dates: Vec<&'static str>) {}
+}
+
+macro_rules! media_optional_dates {
+    ($($media:ident { $($field:ident => $opt:ident)* })*) => {
+        $(
+            impl OptionalDates for $media {
+                const OPTIONAL_DATES: &'static [&'static str] = &[$(stringify!($field)),*];
+
+                fn set_present_dates(&mut self, present_dates: Vec<&'static str>) {
+                    self.present_dates = present_dates
+                }
+            }
+
+            impl $media {
+                $(
+                    #[doc = concat!("[`", stringify!($media), "::", stringify!($field), "`] or [`None`] if the date was missing or")]
+                    #[doc = "null in the api response. Missing dates are defaulting to"]
+                    #[doc = "[`std::time::SystemTime::UNIX_EPOCH`] in the field itself which makes them"]
+                    #[doc = "indistinguishable from a real date otherwise."]
+                    pub fn $opt(&self) -> Option<DateTime<Utc>> {
+                        self.present_dates
+                            .contains(&stringify!($field))
+                            .then_some(self.$field)
+                    }
+                )*
+            }
+        )*
+    }
+}
+
+media_optional_dates! {
+    Episode {
+        episode_air_date => episode_air_date_opt
+        upload_date => upload_date_opt
+        free_available_date => free_available_date_opt
+        premium_available_date => premium_available_date_opt
+        availability_starts => availability_starts_opt
+        availability_ends => availability_ends_opt
+    }
+    MovieListing {
+        free_available_date => free_available_date_opt
+        premium_available_date => premium_available_date_opt
+    }
+    Movie {
+        free_available_date => free_available_date_opt
+        premium_available_date => premium_available_date_opt
+    }
+}
+
+impl OptionalDates for Series {}
+impl OptionalDates for Season {}
+
+macro_rules! media_subtitle_locales {
+    ($($media:ident)*) => {
+        $(
+            impl $media {
+                /// Number of locales in which subtitles are available.
+                pub fn subtitle_locale_count(&self) -> usize {
+                    self.subtitle_locales.len()
+                }
+
+                /// Check if subtitles are available in the given locale.
+                pub fn has_subtitles_in(&self, locale: &$crate::Locale) -> bool {
+                    self.subtitle_locales.contains(locale)
+                }
+            }
+        )*
+    }
+}
+
+media_subtitle_locales! {
+    Series Season Episode MovieListing
+}
+
+macro_rules! media_refresh {
+    ($($media:ident)*) => {
+        $(
+            impl $media {
+                /// Request this media again to get its latest metadata (e.g. changed availability
+                /// dates).
+                pub async fn refresh(&self) -> Result<Self> {
+                    $media::from_id(&$crate::Crunchyroll::from_executor(self.executor.clone()), &self.id).await
+                }
+            }
+        )*
+    }
+}
+
+media_refresh! {
+    Series Season Episode MovieListing Movie
+}
+
+macro_rules! media_url {
+    ($($media:ident => $path:literal)*) => {
+        $(
+            impl $media {
+                /// Url to the Crunchyroll web page of this media. It can be parsed again with
+                /// `parse_url` (requires the `parse` feature).
+                pub fn url(&self) -> String {
+                    format!("https://www.crunchyroll.com/{}/{}/{}", $path, self.id, self.slug_title)
+                }
+            }
+        )*
+    }
+}
+
+media_url! {
+    Series => "series"
+    Episode => "watch"
+    MovieListing => "movie_listing"
+    Movie => "watch"
+}
+
 macro_rules! media_version {
     ($(#[doc=$available_versions_doc:literal] #[doc=$version_doc:literal] #[doc=$versions_doc:literal] $media:ident = $endpoint:literal)*) => {
         $(
@@ -251,7 +503,7 @@ macro_rules! media_version {
                 /// `versions` field is updated with the version of the re-requested struct.
                 async fn assert_versions(&mut self) -> Result<()> {
                     if self.versions.is_none() {
-                        let re_requested = $media::from_id(&$crate::Crunchyroll { executor: self.executor.clone() }, &self.id).await?;
+                        let re_requested = $media::from_id(&$crate::Crunchyroll::from_executor(self.executor.clone()), &self.id).await?;
                         // if the versions are still `None`, no other versions exist
                         self.versions = re_requested.versions.map_or(Some(vec![]), |v| Some(v))
                     }
@@ -278,7 +530,7 @@ macro_rules! media_version {
 
                     let mut result = vec![];
                     for id in version_ids {
-                        result.push($media::from_id(&$crate::Crunchyroll { executor: self.executor.clone() }, id).await?)
+                        result.push($media::from_id(&$crate::Crunchyroll::from_executor(self.executor.clone()), id).await?)
                     }
                     Ok(result)
                 }
@@ -290,7 +542,7 @@ macro_rules! media_version {
 
                     let mut result = vec![];
                     for id in version_ids {
-                        result.push($media::from_id(&$crate::Crunchyroll { executor: self.executor.clone() }, id).await?)
+                        result.push($media::from_id(&$crate::Crunchyroll::from_executor(self.executor.clone()), id).await?)
                     }
                     Ok(result)
                 }
@@ -328,7 +580,7 @@ macro_rules! impl_media_video_collection {
                             let result: $crate::common::V2BulkResult<MediaCollection, PaginationBulkResultMeta> = options
                                 .executor
                                 .get(endpoint)
-                                .query(&[("n", options.page_size), ("start", options.start)])
+                                .query(&options.offset_query())
                                 .apply_locale_query()
                                 .request()
                                 .await?;
@@ -337,6 +589,26 @@ macro_rules! impl_media_video_collection {
                         .boxed()
                     }, self.executor.clone(), None, Some(vec![("id", self.id.clone())]))
                 }
+
+                /// Like [`Series::similar`] / [`MovieListing::similar`] but returns a single page
+                /// of similar media together with the meta information of the response, which is
+                /// discarded by the paginated method. `start` is the index of the first item,
+                /// `limit` the maximum number of items.
+                pub async fn similar_with_meta(&self, start: u32, limit: u32) -> Result<SimilarResult> {
+                    let endpoint = format!("https://www.crunchyroll.com/content/v2/discover/{}/similar_to/{}", self.executor.details.account_id.clone()?, self.id);
+                    let result: $crate::common::V2BulkResult<MediaCollection, SimilarMeta> = self
+                        .executor
+                        .get(endpoint)
+                        .query(&$crate::common::offset_query(limit, start))
+                        .apply_locale_query()
+                        .request()
+                        .await?;
+                    Ok(SimilarResult {
+                        items: result.data,
+                        total: result.total,
+                        meta: result.meta,
+                    })
+                }
             }
         )*
     }
@@ -360,6 +632,38 @@ macro_rules! impl_media_video {
                     $crate::media::Stream::from_legacy_url(self.executor.clone(), &self.stream_id).await
                 }
 
+                /// Like [`Episode::stream`] / [`Movie::stream`] but only with the variants matching
+                /// the given options. [`Episode::stream`] / [`Movie::stream`] return all variants.
+                pub async fn stream_with(&self, options: $crate::media::StreamOptions) -> Result<$crate::media::Stream> {
+                    let mut stream = self.stream().await?;
+                    stream.apply_options(&options);
+                    Ok(stream)
+                }
+
+                /// Like [`Episode::stream`] / [`Movie::stream`] but the result is cached. The cached
+                /// stream is returned until its signed urls are expired (see
+                /// [`crate::media::Stream::is_expired`]), then it's requested again. If no expiry
+                /// can be parsed from the urls of the stream, it's only cached for 10 minutes after
+                /// it was requested. This prevents failing requests
+                /// because of expired urls in long-running tasks like downloads, without
+                /// requesting the stream every time. The cache is shared between clones of this
+                /// struct.
+                pub async fn fresh_stream(&self) -> Result<$crate::media::Stream> {
+                    if let Some((stream, requested_at)) = self.stream_cache.lock().unwrap().as_ref() {
+                        let expired = match stream.expires_at() {
+                            Some(expires_at) => expires_at <= Utc::now(),
+                            None => *requested_at + Duration::try_minutes(FRESH_STREAM_FALLBACK_TTL_MINUTES).unwrap() <= Utc::now(),
+                        };
+                        if !expired {
+                            return Ok(stream.clone())
+                        }
+                    }
+
+                    let stream = self.stream().await?;
+                    *self.stream_cache.lock().unwrap() = Some((stream.clone(), Utc::now()));
+                    Ok(stream)
+                }
+
                 /// Streams for this episode / movie. This endpoint triggers a rate limiting if
                 /// requested too much over a short time period (the rate limiting may occur as an
                 /// error, Crunchyroll doesn't give a hint that a ratelimit is hit). If you need to
@@ -374,7 +678,14 @@ macro_rules! impl_media_video {
 
                 /// Check if the episode / movie can be watched.
                 pub async fn available(&self) -> bool {
-                    self.executor.premium().await || !self.is_premium_only
+                    self.available_with_premium(self.executor.premium().await)
+                }
+
+                /// Like [`Self::available`] but with the premium state of the account already
+                /// known, so that checking multiple episodes / movies doesn't need to get it
+                /// every time.
+                pub(crate) fn available_with_premium(&self, premium: bool) -> bool {
+                    premium || !self.is_premium_only
                 }
 
                 /// Get skippable events like intro or credits.
@@ -433,7 +744,9 @@ macro_rules! impl_media_video {
                 }
 
                 /// Return the next episode / movie. Is [`None`] if the current media is the last in
-                /// its season / has no further media afterwards.
+                /// its season / has no further media afterwards. If a preferred audio locale is set
+                /// ([`crate::crunchyroll::CrunchyrollBuilder::preferred_audio_locale`]), the version
+                /// of the next episode with this audio locale is returned (if available).
                 pub async fn next(&self) -> Result<Option<RelatedMedia<$media_video>>> {
                     let endpoint = format!("https://www.crunchyroll.com/content/v2/discover/up_next/{}", self.id);
                     let result: serde_json::Value = self.executor.get(endpoint)
@@ -446,7 +759,9 @@ macro_rules! impl_media_video {
                         Ok(None)
                     } else {
                         let mut next: $crate::common::V2BulkResult<RelatedMedia<$media_video>> = serde_json::from_value(result)?;
-                        Ok(Some(next.data.remove(0)))
+                        let mut next = next.data.remove(0);
+                        next.media = next.media.into_preferred_audio_version().await?;
+                        Ok(Some(next))
                     }
                 }
 
@@ -467,6 +782,11 @@ macro_rules! impl_media_video {
                 /// is seconds. Setting the playhead also triggers the Crunchyroll Discord
                 /// integration so if you update the playhead and have Crunchyroll connected to
                 /// Discord, this episode / movie will be shown as your Discord status.
+                ///
+                /// Crunchyroll has no api for shared viewing sessions (watch parties / rooms), the
+                /// playhead is always bound to the current account. To sync playback between
+                /// multiple accounts, the playhead must be polled with [`Episode::playhead`] /
+                /// [`Movie::playhead`] and set on every account manually.
                 pub async fn set_playhead(&self, position: u32) -> Result<()> {
                     let endpoint = format!("https://www.crunchyroll.com/content/v2/{}/playheads", self.executor.details.account_id.clone()?);
                     self.executor.post(endpoint)
@@ -476,6 +796,23 @@ macro_rules! impl_media_video {
                         .await?;
                     Ok(())
                 }
+
+                /// Like [`Episode::set_playhead`] / [`Movie::set_playhead`] but takes the position
+                /// as [`chrono::Duration`]. Sub-second parts of the position are floored.
+                pub async fn set_playhead_duration(&self, position: chrono::Duration) -> Result<()> {
+                    self.set_playhead(PlayheadInformation::duration_to_playhead(position)).await
+                }
+
+                /// Delete the playhead of this episode / movie, so that it's shown as unwatched
+                /// again. This only resets the playhead, the entry in the watch history is kept.
+                pub async fn reset_playhead(&self) -> Result<()> {
+                    let endpoint = format!("https://www.crunchyroll.com/content/v2/{}/playheads/{}", self.executor.details.account_id.clone()?, &self.id);
+                    self.executor.delete(endpoint)
+                        .apply_locale_query()
+                        .request::<$crate::EmptyJsonProxy>()
+                        .await?;
+                    Ok(())
+                }
             }
         )*
     }
@@ -1,6 +1,6 @@
 use crate::crunchyroll::Executor;
 use crate::media::util::request_media;
-use crate::media::{Media, ThumbnailImages};
+use crate::media::{Media, MediaId, ThumbnailImages};
 use crate::{Crunchyroll, MovieListing, Result};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
@@ -15,12 +15,23 @@ use std::sync::Arc;
 pub struct Movie {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
+    /// Cache for [`Self::fresh_stream`], with the time the stream was requested.
+    #[serde(skip)]
+    pub(crate) stream_cache: crate::media::StreamCache,
+    /// Names of the optional date fields which were present (and not null) when this struct was
+    /// deserialized, see the `*_opt` date methods.
+    #[serde(skip)]
+    pub(crate) present_dates: Vec<&'static str>,
 
     pub id: String,
     #[serde(alias = "streams_link")]
     #[serde(deserialize_with = "crate::internal::serde::deserialize_streams_link")]
     pub stream_id: String,
     pub channel_id: String,
+    /// Id of this media on the legacy Crunchyroll website (before the 2022 website overhaul),
+    /// prefixed with a type identifier (e.g. `EPI.872893`). Only set for media which already
+    /// existed on the legacy website.
+    pub external_id: Option<String>,
 
     pub slug: String,
     pub title: String,
@@ -34,7 +45,7 @@ pub struct Movie {
     pub movie_listing_slug_title: String,
 
     #[serde(alias = "duration_ms")]
-    #[serde(deserialize_with = "crate::internal::serde::deserialize_millis_to_duration")]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_duration")]
     #[serde(serialize_with = "crate::internal::serde::serialize_duration_to_millis")]
     #[default(Duration::try_milliseconds(0).unwrap())]
     pub duration: Duration,
@@ -59,6 +70,11 @@ pub struct Movie {
     pub available_offline: bool,
     pub availability_notes: String,
 
+    #[serde(rename = "__links__")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub(crate) links: crate::media::MediaLinks,
+
     #[cfg(feature = "__test_strict")]
     #[serde(rename = "type")]
     #[serde(alias = "media_type")]
@@ -66,8 +82,6 @@ pub struct Movie {
     #[cfg(feature = "__test_strict")]
     audio_locale: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
-    external_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
     linked_resource_key: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
     promo_title: Option<crate::StrictValue>,
@@ -96,17 +110,32 @@ impl Movie {
             .await?
             .remove(0))
     }
+
+    /// Returns the parts this movie consists of, in order. Long movies are sometimes split into
+    /// multiple parts on the website, but the api has no field which marks movies as parts of the
+    /// same title; a [`MovieListing`] may just as well contain several separate movies of a
+    /// franchise or extras next to the actual film. Because of this, parts can't be reliably
+    /// detected and this always returns a one-element vec containing the movie itself. Exists to
+    /// be used interchangeably with [`crate::Episode::parts`].
+    pub fn parts(&self) -> Vec<Movie> {
+        vec![self.clone()]
+    }
+
+    /// Movies have no audio versions, so this is always the movie itself. Exists to be used
+    /// interchangeably with [`crate::Episode::into_preferred_audio_version`].
+    pub(crate) async fn into_preferred_audio_version(self) -> Result<Movie> {
+        Ok(self)
+    }
 }
 
 #[async_trait::async_trait]
 impl Media for Movie {
-    async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
+    async fn from_id(crunchyroll: &Crunchyroll, id: impl Into<MediaId> + Send) -> Result<Self> {
+        let id: MediaId = id.into();
+        id.validate()?;
         Ok(request_media(
             crunchyroll.executor.clone(),
-            format!(
-                "https://www.crunchyroll.com/content/v2/cms/movies/{}",
-                id.as_ref()
-            ),
+            format!("https://www.crunchyroll.com/content/v2/cms/movies/{}", id),
         )
         .await?
         .remove(0))
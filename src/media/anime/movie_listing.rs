@@ -1,7 +1,8 @@
 use crate::categories::Category;
 use crate::crunchyroll::Executor;
+use crate::error::Error;
 use crate::media::util::request_media;
-use crate::media::{Media, PosterImages};
+use crate::media::{Media, MediaId, PosterImages, SearchMetadata};
 use crate::{Crunchyroll, Locale, Movie, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -22,6 +23,40 @@ pub(crate) struct MovieListingVersion {
     variant: String,
 }
 
+/// How the movies returned by [`MovieListing::movies_with`] should be sorted.
+#[derive(Clone, Debug)]
+pub enum MovieSortType {
+    /// Sort alphabetically by title.
+    Title,
+    /// Sort by duration, shortest first.
+    Duration,
+    /// Sort by the date the movie became available for premium users, oldest first.
+    PremiumAvailableDate,
+}
+
+/// Options how [`MovieListing::movies_with`] should return movies. Unlike most other options,
+/// these are applied client side as the api doesn't support sorting or filtering movies.
+#[derive(Clone, Debug, Default)]
+pub struct MovieListingMoviesOptions {
+    sort: Option<MovieSortType>,
+    only_available: bool,
+}
+
+impl MovieListingMoviesOptions {
+    /// How the movies should be sorted. If not set, the order of the api result is kept.
+    pub fn sort(mut self, sort: MovieSortType) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Only return movies which can be watched with the current account (see
+    /// [`Movie::available`]).
+    pub fn only_available(mut self, only_available: bool) -> Self {
+        self.only_available = only_available;
+        self
+    }
+}
+
 /// Metadata for a movie listing.
 #[allow(dead_code)]
 #[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault)]
@@ -31,9 +66,17 @@ pub(crate) struct MovieListingVersion {
 pub struct MovieListing {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
+    /// Names of the optional date fields which were present (and not null) when this struct was
+    /// deserialized, see the `*_opt` date methods.
+    #[serde(skip)]
+    pub(crate) present_dates: Vec<&'static str>,
 
     pub id: String,
     pub channel_id: String,
+    /// Id of this media on the legacy Crunchyroll website (before the 2022 website overhaul),
+    /// prefixed with a type identifier (e.g. `SRZ.293765`). Only set for media which already
+    /// existed on the legacy website.
+    pub external_id: Option<String>,
 
     pub slug: String,
     pub title: String,
@@ -46,6 +89,11 @@ pub struct MovieListing {
 
     pub movie_release_year: u32,
 
+    /// Id of the primary movie of this movie listing. Usually a movie listing only contains one
+    /// movie, which is the one this id points to. Might be [`None`] if requested by some functions
+    /// like [`Crunchyroll::browse`].
+    pub first_movie_id: Option<String>,
+
     /// May be [`None`] if requested by some functions like [`Crunchyroll::browse`]. You might have
     /// to re-request it to get the audio locale. Crunchyroll :)
     pub audio_locale: Option<Locale>,
@@ -57,6 +105,11 @@ pub struct MovieListing {
 
     pub images: PosterImages,
 
+    /// Ranking information, only populated if the movie listing was returned by a search or browse
+    /// request.
+    #[serde(default)]
+    pub search_metadata: Option<SearchMetadata>,
+
     #[serde(default)]
     pub keywords: Vec<String>,
     #[serde(default)]
@@ -84,6 +137,11 @@ pub struct MovieListing {
     #[serde(skip_serializing)]
     pub(crate) versions: Option<Vec<MovieListingVersion>>,
 
+    #[serde(rename = "__links__")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub(crate) links: crate::media::MediaLinks,
+
     #[cfg(feature = "__test_strict")]
     extended_maturity_rating: crate::StrictValue,
     #[cfg(feature = "__test_strict")]
@@ -95,10 +153,6 @@ pub struct MovieListing {
     #[cfg(feature = "__test_strict")]
     duration_ms: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
-    external_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    first_movie_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
     new: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
     promo_title: Option<crate::StrictValue>,
@@ -122,7 +176,8 @@ pub struct MovieListing {
 }
 
 impl MovieListing {
-    /// Returns all movies for this movie listing.
+    /// Returns all movies for this movie listing. If a movie is split into multiple parts, every
+    /// part is a separate movie.
     pub async fn movies(&self) -> Result<Vec<Movie>> {
         let endpoint = format!(
             "https://www.crunchyroll.com/content/v2/cms/movie_listings/{}/movies",
@@ -130,16 +185,79 @@ impl MovieListing {
         );
         request_media(self.executor.clone(), endpoint).await
     }
+
+    /// Returns all movies for this movie listing, sorted and filtered as specified in the options.
+    pub async fn movies_with(&self, options: MovieListingMoviesOptions) -> Result<Vec<Movie>> {
+        let mut movies = self.movies().await?;
+
+        if options.only_available {
+            let premium = self.executor.premium().await;
+            movies.retain(|m| m.available_with_premium(premium))
+        }
+        match options.sort {
+            Some(MovieSortType::Title) => movies.sort_by(|a, b| a.title.cmp(&b.title)),
+            Some(MovieSortType::Duration) => movies.sort_by_key(|m| m.duration),
+            Some(MovieSortType::PremiumAvailableDate) => {
+                movies.sort_by_key(|m| m.premium_available_date)
+            }
+            None => (),
+        }
+
+        Ok(movies)
+    }
+
+    /// Returns the movies of this movie listing which can be watched with the current account. A
+    /// movie must be available for the account tier ([`Movie::available`]) and already be released
+    /// for it ([`Movie::premium_available_date`] for premium accounts,
+    /// [`Movie::free_available_date`] otherwise; unset dates count as released). Movies which are
+    /// not available in your region are not returned by the api at all.
+    pub async fn available_movies(&self) -> Result<Vec<Movie>> {
+        let premium = self.executor.premium().await;
+        let now = Utc::now();
+
+        let mut movies = self.movies().await?;
+        movies.retain(|movie| {
+            if !movie.available_with_premium(premium) {
+                return false;
+            }
+            let release_date = if premium {
+                movie.premium_available_date_opt()
+            } else {
+                movie.free_available_date_opt()
+            };
+            !matches!(release_date, Some(date) if date > now)
+        });
+        Ok(movies)
+    }
+
+    /// Returns the primary movie of this movie listing. If [`MovieListing::first_movie_id`] is
+    /// populated, the movie is requested directly, otherwise the first movie of
+    /// [`MovieListing::movies`] is returned.
+    pub async fn first_movie(&self) -> Result<Movie> {
+        if let Some(first_movie_id) = self.first_movie_id.as_ref().filter(|id| !id.is_empty()) {
+            return Movie::from_id(
+                &Crunchyroll::from_executor(self.executor.clone()),
+                first_movie_id,
+            )
+            .await;
+        }
+
+        self.movies().await?.into_iter().next().ok_or(Error::Input {
+            message: "movie listing has no movies".to_string(),
+        })
+    }
 }
 
 #[async_trait::async_trait]
 impl Media for MovieListing {
-    async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
+    async fn from_id(crunchyroll: &Crunchyroll, id: impl Into<MediaId> + Send) -> Result<Self> {
+        let id: MediaId = id.into();
+        id.validate()?;
         Ok(request_media(
             crunchyroll.executor.clone(),
             format!(
                 "https://www.crunchyroll.com/content/v2/cms/movie_listings/{}",
-                id.as_ref()
+                id
             ),
         )
         .await?
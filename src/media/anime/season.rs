@@ -1,8 +1,13 @@
 use crate::crunchyroll::Executor;
+#[cfg(feature = "hls-stream")]
+use crate::error::Error;
 use crate::media::util::request_media;
-use crate::media::Media;
+#[cfg(feature = "hls-stream")]
+use crate::media::StreamPreferences;
+use crate::media::{Media, MediaId, PosterImages};
 use crate::{Crunchyroll, Episode, Locale, Result, Series};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[allow(dead_code)]
@@ -29,6 +34,9 @@ pub(crate) struct SeasonVersion {
 pub struct Season {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
+    /// Cache for [`Season::title_for`].
+    #[serde(skip)]
+    title_cache: Arc<std::sync::Mutex<HashMap<Locale, String>>>,
 
     pub id: String,
     pub series_id: String,
@@ -71,13 +79,21 @@ pub struct Season {
     /// If the season is not available this might contain some information why.
     pub availability_notes: String,
 
+    /// Images of the season. Most seasons have none, use the images of the series
+    /// ([`Series::images`]) as fallback in this case.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_array_to_default")]
+    pub images: PosterImages,
+
     #[serde(default)]
     #[serde(skip_serializing)]
     pub(crate) versions: Option<Vec<SeasonVersion>>,
 
-    #[cfg(feature = "__test_strict")]
-    // currently empty (on all of my tests) but its might be filled in the future
-    images: crate::StrictValue,
+    #[serde(rename = "__links__")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub(crate) links: crate::media::MediaLinks,
+
     #[cfg(feature = "__test_strict")]
     season_display_number: crate::StrictValue,
     #[cfg(feature = "__test_strict")]
@@ -89,6 +105,38 @@ pub struct Season {
 }
 
 impl Season {
+    /// Returns the title of the season in the given locale (e.g. `Season 2` in English, `Staffel
+    /// 2` in German). Titles which were requested once are cached, the cache is shared between
+    /// clones of this struct.
+    pub async fn title_for(&self, locale: &Locale) -> Result<String> {
+        if locale == &self.executor.locale() {
+            return Ok(self.title.clone());
+        }
+        if let Some(title) = self.title_cache.lock().unwrap().get(locale) {
+            return Ok(title.clone());
+        }
+
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/cms/seasons/{}",
+            self.id
+        );
+        let season = self
+            .executor
+            .get(endpoint)
+            .query(&[("locale", locale)])
+            .apply_preferred_audio_locale_query()
+            .request::<crate::common::V2BulkResult<Season>>()
+            .await?
+            .data
+            .remove(0);
+
+        self.title_cache
+            .lock()
+            .unwrap()
+            .insert(locale.clone(), season.title.clone());
+        Ok(season.title)
+    }
+
     /// Returns the series the season belongs to.
     pub async fn series(&self) -> Result<Series> {
         let endpoint = format!(
@@ -100,6 +148,64 @@ impl Season {
             .remove(0))
     }
 
+    /// Returns the season which follows this season in the parent series, ordered by
+    /// [`Season::season_sequence_number`]. If multiple seasons have the same sequence number (e.g.
+    /// dubs which are listed as separate seasons), the one with the same audio locales as this
+    /// season is preferred. Returns [`None`] if this is the last season of the series.
+    pub async fn next_season(&self) -> Result<Option<Season>> {
+        self.adjacent_season(true).await
+    }
+
+    /// Returns the season which precedes this season in the parent series. See
+    /// [`Season::next_season`] for how seasons are ordered. Returns [`None`] if this is the first
+    /// season of the series.
+    pub async fn previous_season(&self) -> Result<Option<Season>> {
+        self.adjacent_season(false).await
+    }
+
+    /// Check if this is the last season of the parent series, i.e. [`Season::next_season`] returns
+    /// [`None`]. For series which are still airing (or get new seasons later on) this is only
+    /// provisional and might change once a new season is added.
+    pub async fn is_last_in_series(&self) -> Result<bool> {
+        Ok(self.next_season().await?.is_none())
+    }
+
+    async fn adjacent_season(&self, next: bool) -> Result<Option<Season>> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/cms/series/{}/seasons",
+            self.series_id
+        );
+        let seasons: Vec<Season> = request_media(self.executor.clone(), endpoint).await?;
+
+        let candidates: Vec<Season> = seasons
+            .into_iter()
+            .filter(|season| {
+                if next {
+                    season.season_sequence_number > self.season_sequence_number
+                } else {
+                    season.season_sequence_number < self.season_sequence_number
+                }
+            })
+            .collect();
+        let Some(sequence_number) = (if next {
+            candidates.iter().map(|s| s.season_sequence_number).min()
+        } else {
+            candidates.iter().map(|s| s.season_sequence_number).max()
+        }) else {
+            return Ok(None);
+        };
+
+        let mut adjacent: Vec<Season> = candidates
+            .into_iter()
+            .filter(|season| season.season_sequence_number == sequence_number)
+            .collect();
+        let position = adjacent
+            .iter()
+            .position(|season| season.audio_locales == self.audio_locales)
+            .unwrap_or_default();
+        Ok(Some(adjacent.remove(position)))
+    }
+
     /// Returns all episodes of this season.
     pub async fn episodes(&self) -> Result<Vec<Episode>> {
         let endpoint = format!(
@@ -108,17 +214,69 @@ impl Season {
         );
         request_media(self.executor.clone(), endpoint).await
     }
+
+    /// Resolves the url of the best (highest resolution) [HLS](https://en.wikipedia.org/wiki/HTTP_Live_Streaming)
+    /// stream of every episode in this season. The urls are resolved concurrently (but at most 4
+    /// at the same time to not trigger any rate limiting) and yielded as soon as they are
+    /// available, so the order of the items may differ from the episode order. Each item consists
+    /// of the episode id and the resolved url or the error which occurred while resolving it.
+    #[cfg(feature = "hls-stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hls-stream")))]
+    pub async fn stream_urls(
+        &self,
+        preferences: StreamPreferences,
+    ) -> Result<impl futures_util::Stream<Item = (String, Result<String>)> + 'static> {
+        use futures_util::StreamExt;
+
+        let episodes = self.episodes().await?;
+
+        Ok(futures_util::stream::iter(episodes)
+            .map(move |episode| {
+                let preferences = preferences.clone();
+                async move {
+                    let id = episode.id.clone();
+                    (id, resolve_stream_url(episode, preferences).await)
+                }
+            })
+            .buffer_unordered(4))
+    }
+}
+
+#[cfg(feature = "hls-stream")]
+async fn resolve_stream_url(
+    mut episode: Episode,
+    preferences: StreamPreferences,
+) -> Result<String> {
+    if let Some(audio) = preferences.audio.clone() {
+        if episode.audio_locale != audio {
+            if let Some(version) = episode.version(vec![audio]).await?.into_iter().next() {
+                episode = version
+            }
+        }
+    }
+
+    let stream = episode.stream().await?;
+    let mut variants = stream
+        .hls_streaming_data(stream.preferred_hardsub(&preferences))
+        .await?;
+    variants.sort_by_key(|v| v.resolution.width);
+
+    variants
+        .pop()
+        .and_then(|v| v.hls_master_url())
+        .ok_or(Error::Input {
+            message: "no stream available".to_string(),
+        })
 }
 
 #[async_trait::async_trait]
 impl Media for Season {
-    async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
+    async fn from_id(crunchyroll: &Crunchyroll, id: impl Into<MediaId> + Send) -> Result<Self> {
+        let id: MediaId = id.into();
+        id.validate()?;
         Ok(request_media(
             crunchyroll.executor.clone(),
-            format!(
-                "https://www.crunchyroll.com/content/v2/cms/seasons/{}",
-                id.as_ref()
-            ),
+            format!("https://www.crunchyroll.com/content/v2/cms/seasons/{}", id),
         )
         .await?
         .remove(0))
@@ -1,8 +1,12 @@
 use crate::categories::Category;
 use crate::crunchyroll::Executor;
+use crate::list::WatchHistoryEntry;
 use crate::media::util::request_media;
-use crate::media::{Media, PosterImages};
-use crate::{Crunchyroll, Locale, MusicVideo, Result, Season};
+use crate::media::{Media, MediaId, PosterImages, SearchMetadata};
+use crate::{Crunchyroll, Episode, Locale, MusicVideo, Result, Season};
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
+use futures_util::{Stream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -18,6 +22,53 @@ pub struct SeriesAward {
     pub is_winner: bool,
 }
 
+/// Comparison of the episode count a [`Season`] claims to have and the number of episodes which
+/// were actually returned when requesting them. Returned as part of [`CountReport`].
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SeasonCountReport {
+    pub season_id: String,
+
+    /// Episode count as stated in [`Season::number_of_episodes`].
+    pub expected_episodes: u32,
+    /// Number of episodes which were returned by [`Season::episodes`].
+    pub actual_episodes: u32,
+}
+
+impl SeasonCountReport {
+    /// If the expected and the actual episode count are matching.
+    pub fn is_consistent(&self) -> bool {
+        self.expected_episodes == self.actual_episodes
+    }
+}
+
+/// Comparison of the season and episode counts a [`Series`] claims to have and the number of
+/// seasons / episodes which were actually returned when requesting them. Mismatches can occur
+/// because the metadata is just wrong or because some seasons / episodes are not available in
+/// your region.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CountReport {
+    /// Season count as stated in [`Series::season_count`].
+    pub expected_seasons: u32,
+    /// Number of seasons which were returned by [`Series::seasons`].
+    pub actual_seasons: u32,
+
+    /// Episode count as stated in [`Series::episode_count`].
+    pub expected_episodes: u32,
+    /// Number of episodes which were returned by [`Season::episodes`] for all seasons.
+    pub actual_episodes: u32,
+
+    pub seasons: Vec<SeasonCountReport>,
+}
+
+impl CountReport {
+    /// If all expected and actual counts, including the ones of every season, are matching.
+    pub fn is_consistent(&self) -> bool {
+        self.expected_seasons == self.actual_seasons
+            && self.expected_episodes == self.actual_episodes
+            && self.seasons.iter().all(|s| s.is_consistent())
+    }
+}
+
 /// Metadata for a series.
 #[allow(dead_code)]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -30,6 +81,10 @@ pub struct Series {
 
     pub id: String,
     pub channel_id: String,
+    /// Id of this media on the legacy Crunchyroll website (before the 2022 website overhaul),
+    /// prefixed with a type identifier (e.g. `SRZ.259963`). Only set for media which already
+    /// existed on the legacy website.
+    pub external_id: Option<String>,
 
     /// Sometimes none, sometimes not
     pub content_provider: Option<String>,
@@ -60,6 +115,11 @@ pub struct Series {
 
     pub images: PosterImages,
 
+    /// Ranking information, only populated if the series was returned by a search or browse
+    /// request.
+    #[serde(default)]
+    pub search_metadata: Option<SearchMetadata>,
+
     #[serde(default)]
     #[serde(rename = "tenant_categories")]
     pub categories: Vec<Category>,
@@ -70,16 +130,22 @@ pub struct Series {
     pub maturity_ratings: Vec<String>,
     pub is_mature: bool,
     pub mature_blocked: bool,
+    /// Content warnings like violence or profanity. Not every series has them.
+    #[serde(default)]
+    pub content_descriptors: Vec<String>,
 
     pub availability_notes: String,
 
     pub awards: Option<Vec<SeriesAward>>,
 
+    #[serde(rename = "__links__")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub(crate) links: crate::media::MediaLinks,
+
     #[cfg(feature = "__test_strict")]
     extended_maturity_rating: crate::StrictValue,
     #[cfg(feature = "__test_strict")]
-    external_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
     last_public: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
     linked_resource_key: Option<crate::StrictValue>,
@@ -92,8 +158,6 @@ pub struct Series {
     #[cfg(feature = "__test_strict")]
     promo_description: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
-    search_metadata: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
     #[serde(rename = "type")]
     _type: Option<crate::StrictValue>,
     #[cfg(feature = "__test_strict")]
@@ -112,6 +176,158 @@ impl Series {
         request_media(self.executor.clone(), endpoint).await
     }
 
+    /// Returns all episodes of all seasons as stream, in season order. The seasons are requested
+    /// once the stream is polled the first time, the episodes of a season not until all episodes
+    /// of the previous season were yielded. So only the episodes of one season are held in memory
+    /// at a time, which makes this suitable to e.g. process series with many episodes one by one.
+    /// If a request fails, its error is yielded and the stream continues with the next season.
+    pub fn episodes_stream(&self) -> BoxStream<'static, Result<Episode>> {
+        let series = self.clone();
+
+        futures_util::stream::once(async move { series.seasons().await })
+            .flat_map(flatten_result)
+            .then(|season| async move { season?.episodes().await })
+            .flat_map(flatten_result)
+            .boxed()
+    }
+
+    /// Returns all episodes of this series which become available in the future, together with
+    /// the date they become available, sorted by that date. The date is the earliest upcoming one
+    /// of [`Episode::premium_available_date`], [`Episode::availability_starts`] and
+    /// [`Episode::free_available_date`]; episodes where all of them are in the past are omitted.
+    /// Note that this makes a request for every season of the series.
+    pub async fn content_availability_calendar(&self) -> Result<Vec<(Episode, DateTime<Utc>)>> {
+        let now = Utc::now();
+        let episodes: Vec<Episode> = self.episodes_stream().try_collect().await?;
+
+        let mut calendar: Vec<(Episode, DateTime<Utc>)> = episodes
+            .into_iter()
+            .filter_map(|episode| {
+                let date = [
+                    episode.premium_available_date_opt(),
+                    episode.availability_starts_opt(),
+                    episode.free_available_date_opt(),
+                ]
+                .into_iter()
+                .flatten()
+                .filter(|date| date > &now)
+                .min()?;
+                Some((episode, date))
+            })
+            .collect();
+        calendar.sort_by_key(|(_, date)| *date);
+        Ok(calendar)
+    }
+
+    /// Check if this series is currently airing as simulcast, as opposed to series which were
+    /// simulcasted once but have finished airing. Crunchyroll has no field for this, so it's
+    /// determined by a heuristic: the series must be a simulcast ([`Series::is_simulcast`]), its
+    /// latest season must not be marked as complete ([`Season::is_complete`]) and the latest
+    /// episode of this season must either be announced for the future or must have aired
+    /// ([`Episode::episode_air_date`] or, if not set, [`Episode::premium_available_date`]) within
+    /// the last 14 days. Breaks in the release schedule longer than that are thus detected as not
+    /// airing. Note that this makes a request for the seasons and one for the episodes of the
+    /// latest season.
+    pub async fn is_currently_airing(&self) -> Result<bool> {
+        if !self.is_simulcast {
+            return Ok(false);
+        }
+
+        let Some(season) = self
+            .seasons()
+            .await?
+            .into_iter()
+            .max_by_key(|season| season.season_sequence_number)
+        else {
+            return Ok(false);
+        };
+        if season.is_complete {
+            return Ok(false);
+        }
+
+        let latest_air_date = season
+            .episodes()
+            .await?
+            .iter()
+            .filter_map(|episode| {
+                episode
+                    .episode_air_date_opt()
+                    .or(episode.premium_available_date_opt())
+            })
+            .max();
+        Ok(latest_air_date.is_some_and(|date| date > Utc::now() - chrono::Duration::days(14)))
+    }
+
+    /// Returns the entries of your watch history which belong to this series. The watch history
+    /// endpoint can't be filtered by series, so the whole history is requested (page by page) and
+    /// filtered client-side; this might take some time if your history is long.
+    pub async fn watch_history(&self) -> Result<Vec<WatchHistoryEntry>> {
+        Crunchyroll::from_executor(self.executor.clone())
+            .watch_history()
+            .try_filter(|entry| futures_util::future::ready(entry.parent_id == self.id))
+            .try_collect()
+            .await
+    }
+
+    /// Returns the fraction (`0.0` to `1.0`) of episodes of this series you've watched. An episode
+    /// counts as watched if Crunchyroll marks it as fully watched or if its playhead is at 90% or
+    /// more of its duration; partially watched episodes below that don't count at all. The
+    /// episodes are the ones of all seasons (see [`Series::episodes_stream`]). Note that this
+    /// makes a request for every season of the series plus one request per 50 episodes to get
+    /// their playheads (see [`Crunchyroll::playheads_for`]). Returns `0.0` if the series has no
+    /// episodes.
+    pub async fn progress(&self) -> Result<f32> {
+        let episodes: Vec<Episode> = self.episodes_stream().try_collect().await?;
+        if episodes.is_empty() {
+            return Ok(0.0);
+        }
+
+        let ids: Vec<String> = episodes.iter().map(|episode| episode.id.clone()).collect();
+        let playheads = Crunchyroll::from_executor(self.executor.clone())
+            .playheads_for(&ids)
+            .await?;
+
+        let watched = episodes
+            .iter()
+            .filter(|episode| {
+                playheads
+                    .get(&episode.id)
+                    .and_then(Option::as_ref)
+                    .is_some_and(|playhead| {
+                        playhead.fully_watched
+                            || (episode.duration > chrono::Duration::zero()
+                                && playhead.playhead_duration() >= episode.duration * 9 / 10)
+                    })
+            })
+            .count();
+        Ok(watched as f32 / episodes.len() as f32)
+    }
+
+    /// Request all seasons and their episodes and compare their number with the counts stated in
+    /// [`Series::season_count`], [`Series::episode_count`] and [`Season::number_of_episodes`].
+    /// Note that this makes a request for every season of the series.
+    pub async fn verify_counts(&self) -> Result<CountReport> {
+        let seasons = self.seasons().await?;
+
+        let mut report = CountReport {
+            expected_seasons: self.season_count,
+            actual_seasons: seasons.len() as u32,
+            expected_episodes: self.episode_count,
+            ..Default::default()
+        };
+        for season in seasons {
+            let actual_episodes = season.episodes().await?.len() as u32;
+            report.actual_episodes += actual_episodes;
+            report.seasons.push(SeasonCountReport {
+                season_id: season.id,
+                expected_episodes: season.number_of_episodes,
+                actual_episodes,
+            })
+        }
+
+        Ok(report)
+    }
+
     /// Get music videos which are related to this series.
     pub async fn featured_music(&self) -> Result<Vec<MusicVideo>> {
         let endpoint = format!(
@@ -122,15 +338,21 @@ impl Series {
     }
 }
 
+fn flatten_result<T>(result: Result<Vec<T>>) -> impl Stream<Item = Result<T>> {
+    futures_util::stream::iter(match result {
+        Ok(items) => items.into_iter().map(Ok).collect(),
+        Err(e) => vec![Err(e)],
+    })
+}
+
 #[async_trait::async_trait]
 impl Media for Series {
-    async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
+    async fn from_id(crunchyroll: &Crunchyroll, id: impl Into<MediaId> + Send) -> Result<Self> {
+        let id: MediaId = id.into();
+        id.validate()?;
         Ok(request_media(
             crunchyroll.executor.clone(),
-            format!(
-                "https://www.crunchyroll.com/content/v2/cms/series/{}",
-                id.as_ref()
-            ),
+            format!("https://www.crunchyroll.com/content/v2/cms/series/{}", id),
         )
         .await?
         .remove(0))
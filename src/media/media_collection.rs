@@ -1,7 +1,7 @@
-use crate::common::Request;
+use crate::common::{Image, Request};
 use crate::crunchyroll::Executor;
 use crate::error::Error;
-use crate::media::Media;
+use crate::media::{Media, MediaId, SearchMetadata};
 use crate::{
     Concert, Crunchyroll, Episode, Movie, MovieListing, MusicVideo, Result, Season, Series,
 };
@@ -9,10 +9,32 @@ use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
 
+/// Fields which every media type in [`MediaCollection`] has. Returned by
+/// [`MediaCollection::as_media_base`].
+#[derive(Clone, Debug)]
+pub struct MediaBase<'a> {
+    pub id: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+
+    /// All images of the media. Mostly empty for [`Season`]s as most of them don't have any images.
+    pub images: Vec<&'a Image>,
+}
+
 /// Collection of all media types. Useful in situations where [`Media`] can contain more than one
 /// specific media.
+///
+/// New media types might be added in the future, so matching it requires a wildcard arm. If you
+/// only need data which all media types have, consider using [`MediaCollection::as_media_base`]
+/// instead of matching.
+///
+/// When deserializing, the media type is detected by (in this priority) the `type` field, the
+/// `*_metadata` block (`episode_metadata` > `season_metadata` > `series_metadata` >
+/// `movie_metadata` > `movie_listing_metadata`) and fields only a specific media type has. Media
+/// which is wrapped in a `{ "panel": { ... } }` object is unwrapped before.
 #[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Clone, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MediaCollection {
     Series(Series),
     Season(Season),
@@ -23,24 +45,48 @@ pub enum MediaCollection {
     Concert(Concert),
 }
 
+/// Lightweight representation of the media type in a [`MediaCollection`], returned by
+/// [`MediaCollection::kind`]. Music media ([`MusicVideo`] and [`Concert`]) share one kind, use
+/// [`MusicKind`] to distinguish them.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum MediaKind {
+    Series,
+    Season,
+    Episode,
+    MovieListing,
+    Movie,
+    Music(MusicKind),
+}
+
+/// Type of a music media, see [`MediaKind::Music`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum MusicKind {
+    Video,
+    Concert,
+}
+
 impl MediaCollection {
-    pub async fn from_id<S: AsRef<str>>(
+    pub async fn from_id(
         crunchyroll: &Crunchyroll,
-        id: S,
+        id: impl Into<MediaId> + Send,
     ) -> Result<MediaCollection> {
-        if let Ok(episode) = Episode::from_id(crunchyroll, id.as_ref()).await {
+        let id: MediaId = id.into();
+        id.validate()?;
+        if let Ok(episode) = Episode::from_id(crunchyroll, &id).await {
             Ok(MediaCollection::Episode(episode))
-        } else if let Ok(movie) = Movie::from_id(crunchyroll, id.as_ref()).await {
+        } else if let Ok(movie) = Movie::from_id(crunchyroll, &id).await {
             Ok(MediaCollection::Movie(movie))
-        } else if let Ok(series) = Series::from_id(crunchyroll, id.as_ref()).await {
+        } else if let Ok(series) = Series::from_id(crunchyroll, &id).await {
             Ok(MediaCollection::Series(series))
-        } else if let Ok(season) = Season::from_id(crunchyroll, id.as_ref()).await {
+        } else if let Ok(season) = Season::from_id(crunchyroll, &id).await {
             Ok(MediaCollection::Season(season))
-        } else if let Ok(movie_listing) = MovieListing::from_id(crunchyroll, id.as_ref()).await {
+        } else if let Ok(movie_listing) = MovieListing::from_id(crunchyroll, &id).await {
             Ok(MediaCollection::MovieListing(movie_listing))
-        } else if let Ok(concert) = Concert::from_id(crunchyroll, id.as_ref()).await {
+        } else if let Ok(concert) = Concert::from_id(crunchyroll, &id).await {
             Ok(MediaCollection::Concert(concert))
-        } else if let Ok(music_video) = MusicVideo::from_id(crunchyroll, id.as_ref()).await {
+        } else if let Ok(music_video) = MusicVideo::from_id(crunchyroll, &id).await {
             Ok(MediaCollection::MusicVideo(music_video))
         } else {
             Err(Error::Input {
@@ -48,6 +94,95 @@ impl MediaCollection {
             })
         }
     }
+
+    /// Return the kind of the media without moving or cloning the inner value. Useful if only the
+    /// type of the media is needed, e.g. to decide how it should be rendered.
+    pub fn kind(&self) -> MediaKind {
+        match self {
+            MediaCollection::Series(_) => MediaKind::Series,
+            MediaCollection::Season(_) => MediaKind::Season,
+            MediaCollection::Episode(_) => MediaKind::Episode,
+            MediaCollection::MovieListing(_) => MediaKind::MovieListing,
+            MediaCollection::Movie(_) => MediaKind::Movie,
+            MediaCollection::MusicVideo(_) => MediaKind::Music(MusicKind::Video),
+            MediaCollection::Concert(_) => MediaKind::Music(MusicKind::Concert),
+        }
+    }
+
+    /// Return the search ranking information of the media, regardless of its type. Only
+    /// [`Series`], [`Episode`] and [`MovieListing`] can have it and only if they were returned by a
+    /// search or browse request.
+    pub fn search_metadata(&self) -> Option<&SearchMetadata> {
+        match self {
+            MediaCollection::Series(series) => series.search_metadata.as_ref(),
+            MediaCollection::Episode(episode) => episode.search_metadata.as_ref(),
+            MediaCollection::MovieListing(movie_listing) => movie_listing.search_metadata.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Return the fields which every media type has.
+    pub fn as_media_base(&self) -> MediaBase<'_> {
+        match self {
+            MediaCollection::Series(series) => MediaBase {
+                id: &series.id,
+                title: &series.title,
+                description: &series.description,
+                images: series
+                    .images
+                    .poster_tall
+                    .iter()
+                    .chain(series.images.poster_wide.iter())
+                    .collect(),
+            },
+            MediaCollection::Season(season) => MediaBase {
+                id: &season.id,
+                title: &season.title,
+                description: &season.description,
+                images: season
+                    .images
+                    .poster_tall
+                    .iter()
+                    .chain(season.images.poster_wide.iter())
+                    .collect(),
+            },
+            MediaCollection::Episode(episode) => MediaBase {
+                id: &episode.id,
+                title: &episode.title,
+                description: &episode.description,
+                images: episode.images.iter().collect(),
+            },
+            MediaCollection::MovieListing(movie_listing) => MediaBase {
+                id: &movie_listing.id,
+                title: &movie_listing.title,
+                description: &movie_listing.description,
+                images: movie_listing
+                    .images
+                    .poster_tall
+                    .iter()
+                    .chain(movie_listing.images.poster_wide.iter())
+                    .collect(),
+            },
+            MediaCollection::Movie(movie) => MediaBase {
+                id: &movie.id,
+                title: &movie.title,
+                description: &movie.description,
+                images: movie.images.thumbnail.iter().collect(),
+            },
+            MediaCollection::MusicVideo(music_video) => MediaBase {
+                id: &music_video.id,
+                title: &music_video.title,
+                description: &music_video.description,
+                images: music_video.images.thumbnail.iter().collect(),
+            },
+            MediaCollection::Concert(concert) => MediaBase {
+                id: &concert.id,
+                title: &concert.title,
+                description: &concert.description,
+                images: concert.images.thumbnail.iter().collect(),
+            },
+        }
+    }
 }
 
 impl Default for MediaCollection {
@@ -56,56 +191,93 @@ impl Default for MediaCollection {
     }
 }
 
+impl MediaKind {
+    /// Detect the kind of media a payload represents. Payloads may contain fields which belong to
+    /// multiple media kinds (e.g. an episode panel has `series_id` or a stray metadata block), so
+    /// the detection follows a fixed priority, the first matching rule wins:
+    /// 1. The `type` field, if it's one of `series`, `season`, `episode`, `movie_listing`,
+    ///    `movie`, `musicVideo` or `musicConcert`.
+    /// 2. The `*_metadata` blocks, in the order `episode_metadata`, `season_metadata`,
+    ///    `series_metadata`, `movie_metadata`, `movie_listing_metadata`.
+    /// 3. Fields which only a specific kind has when the payload isn't a panel, in the order
+    ///    `series_launch_year` (series), `number_of_episodes` (season), `sequence_number`
+    ///    (episode), `movie_release_year` (movie listing), `movie_listing_title` (movie),
+    ///    `animeIds` (music video), `availability` (concert).
+    fn detect(map: &serde_json::Map<String, Value>) -> Option<Self> {
+        let by_type = match map.get("type").and_then(|t| t.as_str()) {
+            Some("series") => Some(Self::Series),
+            Some("season") => Some(Self::Season),
+            Some("episode") => Some(Self::Episode),
+            Some("movie_listing") => Some(Self::MovieListing),
+            Some("movie") => Some(Self::Movie),
+            Some("musicVideo") => Some(Self::Music(MusicKind::Video)),
+            Some("musicConcert") => Some(Self::Music(MusicKind::Concert)),
+            _ => None,
+        };
+        if by_type.is_some() {
+            return by_type;
+        }
+
+        [
+            ("episode_metadata", Self::Episode),
+            ("season_metadata", Self::Season),
+            ("series_metadata", Self::Series),
+            ("movie_metadata", Self::Movie),
+            ("movie_listing_metadata", Self::MovieListing),
+            ("series_launch_year", Self::Series),
+            ("number_of_episodes", Self::Season),
+            ("sequence_number", Self::Episode),
+            ("movie_release_year", Self::MovieListing),
+            ("movie_listing_title", Self::Movie),
+            ("animeIds", Self::Music(MusicKind::Video)),
+            // music video contains this field too so music video must be checked before this
+            ("availability", Self::Music(MusicKind::Concert)),
+        ]
+        .into_iter()
+        .find(|(key, _)| map.contains_key(*key))
+        .map(|(_, kind)| kind)
+    }
+}
+
 impl<'de> Deserialize<'de> for MediaCollection {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let as_map = serde_json::Map::deserialize(deserializer)?;
+        let as_map =
+            crate::internal::serde::unwrap_panel(serde_json::Map::deserialize(deserializer)?);
 
         let err_conv = |e: serde_json::Error| serde::de::Error::custom(e.to_string());
 
-        if as_map.contains_key("series_metadata") || as_map.contains_key("series_launch_year") {
-            Ok(MediaCollection::Series(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
-            ))
-        } else if as_map.contains_key("season_metadata")
-            || as_map.contains_key("number_of_episodes")
-        {
-            Ok(MediaCollection::Season(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
-            ))
-        } else if as_map.contains_key("episode_metadata") || as_map.contains_key("sequence_number")
-        {
-            Ok(MediaCollection::Episode(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
-            ))
-        } else if as_map.contains_key("movie_listing_metadata")
-            || as_map.contains_key("movie_release_year")
-        {
-            Ok(MediaCollection::MovieListing(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
-            ))
-        } else if as_map.contains_key("movie_metadata")
-            || as_map.contains_key("movie_listing_title")
-        {
-            Ok(MediaCollection::Movie(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
-            ))
-        } else if as_map.contains_key("animeIds") {
-            Ok(MediaCollection::MusicVideo(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
-            ))
-        // music video contains this field too so music video must be checked before this condition
-        } else if as_map.contains_key("availability") {
-            Ok(MediaCollection::Concert(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
-            ))
-        } else {
-            Err(serde::de::Error::custom(
+        let Some(kind) = MediaKind::detect(&as_map) else {
+            return Err(serde::de::Error::custom(
                 "could not deserialize into media collection".to_string(),
-            ))
-        }
+            ));
+        };
+        let value = Value::from(as_map);
+        Ok(match kind {
+            MediaKind::Series => {
+                MediaCollection::Series(serde_json::from_value(value).map_err(err_conv)?)
+            }
+            MediaKind::Season => {
+                MediaCollection::Season(serde_json::from_value(value).map_err(err_conv)?)
+            }
+            MediaKind::Episode => {
+                MediaCollection::Episode(serde_json::from_value(value).map_err(err_conv)?)
+            }
+            MediaKind::MovieListing => {
+                MediaCollection::MovieListing(serde_json::from_value(value).map_err(err_conv)?)
+            }
+            MediaKind::Movie => {
+                MediaCollection::Movie(serde_json::from_value(value).map_err(err_conv)?)
+            }
+            MediaKind::Music(MusicKind::Video) => {
+                MediaCollection::MusicVideo(serde_json::from_value(value).map_err(err_conv)?)
+            }
+            MediaKind::Music(MusicKind::Concert) => {
+                MediaCollection::Concert(serde_json::from_value(value).map_err(err_conv)?)
+            }
+        })
     }
 }
 
@@ -17,6 +17,10 @@ pub use stream::*;
 #[cfg(any(feature = "hls-stream", feature = "dash-stream"))]
 pub use streaming::*;
 
+#[cfg(feature = "parse")]
+use crate::error::Error;
+#[cfg(feature = "parse")]
+use crate::UrlType;
 use crate::{Crunchyroll, Result};
 
 crate::enum_values! {
@@ -27,11 +31,20 @@ crate::enum_values! {
     }
 }
 
+crate::enum_values! {
+    /// Channel a media is published on. Currently all media on Crunchyroll is published on the
+    /// [`Channel::Crunchyroll`] channel, other values are still kept as [`Channel::Custom`] in
+    /// case Crunchyroll starts to use them (e.g. for partner content).
+    pub enum Channel {
+        Crunchyroll = "crunchyroll"
+    }
+}
+
 /// Trait every media struct ([`Series`], [`Season`], [`Episode`], [`MovieListing`], [`Movie`],
 /// [`MusicVideo`], [`Concert`]) implements.
 #[async_trait::async_trait]
 pub trait Media {
-    async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self>
+    async fn from_id(crunchyroll: &Crunchyroll, id: impl Into<MediaId> + Send) -> Result<Self>
     where
         Self: Sized;
 
@@ -44,11 +57,69 @@ pub trait Media {
 }
 
 impl Crunchyroll {
-    pub async fn media_from_id<M: Media>(&self, id: impl AsRef<str> + Send) -> Result<M> {
+    pub async fn media_from_id<M: Media>(&self, id: impl Into<MediaId> + Send) -> Result<M> {
         M::from_id(self, id).await
     }
 
-    pub async fn media_collection_from_id<S: AsRef<str>>(&self, id: S) -> Result<MediaCollection> {
+    pub async fn media_collection_from_id(
+        &self,
+        id: impl Into<MediaId> + Send,
+    ) -> Result<MediaCollection> {
         MediaCollection::from_id(self, id).await
     }
+
+    /// Resolve the id of an episode or movie on the legacy Crunchyroll website (e.g. `759575` or
+    /// `EPI.759575`, see [`Episode::external_id`]) to its current media. Legacy series ids (`SRZ.`
+    /// prefix) are not supported as the legacy website had no id based series urls.
+    ///
+    /// Crunchyroll has no api endpoint to look up legacy ids, so this relies on the website: the
+    /// legacy media url (`https://www.crunchyroll.com/media-<id>`) is requested, which the website
+    /// redirects to the current url of the media, and the url it got redirected to is parsed.
+    /// If the website responds with an error status, [`Error::Request`] is returned; if it
+    /// doesn't redirect at all or redirects to a url which isn't a media url (e.g. because the
+    /// legacy id is unknown), [`Error::Input`] is returned. This breaks if Crunchyroll ever drops
+    /// the legacy redirects.
+    #[cfg(feature = "parse")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parse")))]
+    pub async fn media_from_external_id(&self, external_id: &str) -> Result<MediaCollection> {
+        let legacy_id = external_id.strip_prefix("EPI.").unwrap_or(external_id);
+        if legacy_id.is_empty() || !legacy_id.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Error::Input {
+                message: format!("'{external_id}' is no legacy episode or movie id"),
+            });
+        }
+
+        let legacy_url = format!("https://www.crunchyroll.com/media-{legacy_id}");
+        let url = self
+            .executor
+            .get(&legacy_url)
+            .request_redirect_url()
+            .await?
+            .to_string();
+        if url == legacy_url {
+            return Err(Error::Input {
+                message: format!("legacy id '{external_id}' wasn't redirected to any media"),
+            });
+        }
+        match crate::parse_url(&url) {
+            Some(UrlType::EpisodeOrMovie(id)) => MediaCollection::from_id(self, id).await,
+            Some(UrlType::Series(id)) => {
+                Ok(MediaCollection::Series(Series::from_id(self, id).await?))
+            }
+            Some(UrlType::MovieListing(id)) => Ok(MediaCollection::MovieListing(
+                MovieListing::from_id(self, id).await?,
+            )),
+            Some(UrlType::MusicVideo(id)) => Ok(MediaCollection::MusicVideo(
+                MusicVideo::from_id(self, id).await?,
+            )),
+            Some(UrlType::Concert(id)) => {
+                Ok(MediaCollection::Concert(Concert::from_id(self, id).await?))
+            }
+            None => Err(Error::Input {
+                message: format!(
+                    "could not resolve legacy id '{external_id}' (redirected to '{url}')"
+                ),
+            }),
+        }
+    }
 }
@@ -2,7 +2,7 @@ use crate::common::V2BulkResult;
 use crate::crunchyroll::Executor;
 use crate::media::music::concert::Concert;
 use crate::media::util::request_media;
-use crate::media::{MusicGenre, MusicVideo, PosterImages};
+use crate::media::{MediaId, MusicGenre, MusicVideo, PosterImages};
 use crate::{Crunchyroll, Request, Result};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
@@ -41,13 +41,7 @@ pub struct ArtistPreview {
 
 impl ArtistPreview {
     pub async fn artist(&self) -> Result<Artist> {
-        Artist::from_id(
-            &Crunchyroll {
-                executor: self.executor.clone(),
-            },
-            &self.id,
-        )
-        .await
+        Artist::from_id(&Crunchyroll::from_executor(self.executor.clone()), &self.id).await
     }
 }
 
@@ -79,12 +73,12 @@ pub struct Artist {
     pub publish_date: DateTime<Utc>,
 
     #[serde(alias = "totalConcertDurationMs")]
-    #[serde(deserialize_with = "crate::internal::serde::deserialize_millis_to_duration")]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_duration")]
     #[serde(serialize_with = "crate::internal::serde::serialize_duration_to_millis")]
     #[default(Duration::try_milliseconds(0).unwrap())]
     pub total_concert_duration: Duration,
     #[serde(alias = "totalVideoDurationMs")]
-    #[serde(deserialize_with = "crate::internal::serde::deserialize_millis_to_duration")]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_duration")]
     #[serde(serialize_with = "crate::internal::serde::serialize_duration_to_millis")]
     #[default(Duration::try_milliseconds(0).unwrap())]
     pub total_video_duration: Duration,
@@ -101,10 +95,12 @@ pub struct Artist {
 }
 
 impl Artist {
-    pub async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
+    pub async fn from_id(crunchyroll: &Crunchyroll, id: impl Into<MediaId> + Send) -> Result<Self> {
+        let id: MediaId = id.into();
+        id.validate()?;
         let endpoint = format!(
             "https://www.crunchyroll.com/content/v2/music/artists/{}",
-            id.as_ref()
+            id
         );
         Ok(request_media(crunchyroll.executor.clone(), endpoint)
             .await?
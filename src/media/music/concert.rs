@@ -1,7 +1,9 @@
 use crate::crunchyroll::Executor;
 use crate::media::music::util::availability_object_to_keys;
 use crate::media::util::request_media;
-use crate::media::{ArtistPreview, ArtistsPreviewList, Media, MusicGenre, ThumbnailImages};
+use crate::media::{
+    ArtistPreview, ArtistsPreviewList, Media, MediaId, MusicGenre, ThumbnailImages,
+};
 use crate::{Crunchyroll, Request, Result};
 use chrono::{DateTime, Duration, Utc};
 use serde::de::{Error, IntoDeserializer};
@@ -49,7 +51,7 @@ pub struct Concert {
     pub publish_date: DateTime<Utc>,
 
     #[serde(alias = "durationMs")]
-    #[serde(deserialize_with = "crate::internal::serde::deserialize_millis_to_duration")]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_duration")]
     #[serde(serialize_with = "crate::internal::serde::serialize_duration_to_millis")]
     #[default(Duration::try_milliseconds(0).unwrap())]
     pub duration: Duration,
@@ -98,10 +100,12 @@ impl<'de> Deserialize<'de> for Concert {
 
 #[async_trait::async_trait]
 impl Media for Concert {
-    async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
+    async fn from_id(crunchyroll: &Crunchyroll, id: impl Into<MediaId> + Send) -> Result<Self> {
+        let id: MediaId = id.into();
+        id.validate()?;
         let endpoint = format!(
             "https://www.crunchyroll.com/content/v2/music/concerts/{}",
-            id.as_ref()
+            id
         );
         Ok(request_media(crunchyroll.executor.clone(), endpoint)
             .await?
@@ -37,6 +37,11 @@ macro_rules! impl_media_music {
                 pub async fn available(&self) -> bool {
                     self.executor.premium().await || !self.is_premium_only
                 }
+
+                /// Request this music video / concert again to get its latest metadata.
+                pub async fn refresh(&self) -> Result<Self> {
+                    <$media_music as $crate::media::Media>::from_id(&$crate::Crunchyroll::from_executor(self.executor.clone()), &self.id).await
+                }
             }
         )*
     }
@@ -61,3 +66,22 @@ macro_rules! music_eq {
 music_eq! {
     MusicVideo Concert Artist
 }
+
+macro_rules! music_url {
+    ($($media:ident => $path:literal)*) => {
+        $(
+            impl $media {
+                /// Url to the Crunchyroll web page of this music video / concert. It can be parsed
+                /// again with `parse_url` (requires the `parse` feature).
+                pub fn url(&self) -> String {
+                    format!("https://www.crunchyroll.com/{}/{}/{}", $path, self.id, self.slug)
+                }
+            }
+        )*
+    }
+}
+
+music_url! {
+    MusicVideo => "watch/musicvideo"
+    Concert => "watch/concert"
+}
@@ -1,7 +1,9 @@
 use crate::crunchyroll::Executor;
 use crate::media::music::util::availability_object_to_keys;
 use crate::media::util::request_media;
-use crate::media::{ArtistPreview, ArtistsPreviewList, Media, MusicGenre, ThumbnailImages};
+use crate::media::{
+    ArtistPreview, ArtistsPreviewList, Media, MediaId, MusicGenre, ThumbnailImages,
+};
 use crate::{Crunchyroll, MediaCollection, Request, Result};
 use chrono::{DateTime, Duration, Utc};
 use serde::de::{Error, IntoDeserializer};
@@ -53,7 +55,7 @@ pub struct MusicVideo {
     pub original_release: DateTime<Utc>,
 
     #[serde(alias = "durationMs")]
-    #[serde(deserialize_with = "crate::internal::serde::deserialize_millis_to_duration")]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_duration")]
     #[serde(serialize_with = "crate::internal::serde::serialize_duration_to_millis")]
     #[default(Duration::try_milliseconds(0).unwrap())]
     pub duration: Duration,
@@ -106,9 +108,7 @@ impl MusicVideo {
         for id in &self.anime_ids {
             media.push(
                 Crunchyroll::media_collection_from_id(
-                    &Crunchyroll {
-                        executor: self.executor.clone(),
-                    },
+                    &Crunchyroll::from_executor(self.executor.clone()),
                     id,
                 )
                 .await?,
@@ -121,10 +121,12 @@ impl MusicVideo {
 
 #[async_trait::async_trait]
 impl Media for MusicVideo {
-    async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
+    async fn from_id(crunchyroll: &Crunchyroll, id: impl Into<MediaId> + Send) -> Result<Self> {
+        let id: MediaId = id.into();
+        id.validate()?;
         let endpoint = format!(
             "https://www.crunchyroll.com/content/v2/music/music_videos/{}",
-            id.as_ref()
+            id
         );
         Ok(request_media(crunchyroll.executor.clone(), endpoint)
             .await?
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Links to resources which are related to a media. Crunchyroll delivers them mostly as relative
+/// paths but sometimes as absolute urls; relative paths are converted to absolute urls here. Every
+/// link is [`None`] if the media has no such resource or Crunchyroll did not include it in the
+/// response.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(try_from = "Map<String, Value>")]
+pub struct MediaLinks {
+    /// Link to the streams of an [`crate::Episode`] or [`crate::Movie`].
+    pub streams: Option<String>,
+    /// Link to the channel the media belongs to.
+    pub channel: Option<String>,
+
+    /// Link to the series a [`crate::Season`] or [`crate::Episode`] belongs to.
+    pub series: Option<String>,
+    /// Link to the seasons of a [`crate::Series`].
+    pub seasons: Option<String>,
+    /// Link to the season an [`crate::Episode`] belongs to.
+    pub season: Option<String>,
+    /// Link to the episodes of a [`crate::Season`].
+    pub episodes: Option<String>,
+    /// Link to the next episode of an [`crate::Episode`].
+    pub next_episode: Option<String>,
+
+    /// Link to the movie listing a [`crate::Movie`] belongs to.
+    pub movie_listing: Option<String>,
+    /// Link to the movies of a [`crate::MovieListing`].
+    pub movies: Option<String>,
+
+    /// All links which are not covered by the other fields. The key is the raw name Crunchyroll
+    /// uses for the link, e.g. `episode/channel`.
+    pub other: HashMap<String, String>,
+}
+
+impl TryFrom<Map<String, Value>> for MediaLinks {
+    type Error = serde_json::Error;
+
+    fn try_from(value: Map<String, Value>) -> Result<Self, Self::Error> {
+        let mut links = MediaLinks::default();
+
+        for (name, link) in value {
+            let Some(href) = link.get("href").and_then(|h| h.as_str()) else {
+                continue;
+            };
+            let url = if href.starts_with('/') {
+                format!("https://www.crunchyroll.com{}", href)
+            } else {
+                href.to_string()
+            };
+
+            // the names are something like 'episode/season' where the part before the slash is the
+            // type of the media which contains the link
+            let field = match name.rsplit('/').next().unwrap_or_default() {
+                "streams" => &mut links.streams,
+                "channel" => &mut links.channel,
+                "series" => &mut links.series,
+                "seasons" => &mut links.seasons,
+                "season" => &mut links.season,
+                "episodes" => &mut links.episodes,
+                "next_episode" => &mut links.next_episode,
+                "movie_listing" => &mut links.movie_listing,
+                "movies" => &mut links.movies,
+                _ => {
+                    links.other.insert(name, url);
+                    continue;
+                }
+            };
+            *field = Some(url)
+        }
+
+        Ok(links)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{Crunchyroll, Locale, Result};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Human readable definition of a maturity rating code like they are used in the
+/// `maturity_ratings` fields of media (e.g. [`crate::Series::maturity_ratings`]).
+#[derive(Clone, Debug, Serialize)]
+pub struct MaturityRatingDefinition {
+    /// Short name of the rating, e.g. `Parents Strongly Cautioned`.
+    pub name: String,
+    /// Longer description who the content is suitable for.
+    pub description: String,
+}
+
+/// Codes of the US TV parental guidelines, in the same order as the entries of
+/// [`localized_definitions`].
+const CODES: [&str; 6] = ["TV-Y", "TV-Y7", "TV-G", "TV-PG", "TV-14", "TV-MA"];
+
+impl Crunchyroll {
+    /// Return definitions for the maturity rating codes Crunchyroll uses, keyed by the code (e.g.
+    /// `TV-14`), in the language of [`Crunchyroll::locale`].
+    ///
+    /// Crunchyroll has no api endpoint which provides these definitions, so this doesn't make any
+    /// request and returns a static table which is bundled with this crate. Only the codes of the
+    /// US TV parental guidelines (which are used by Crunchyroll in most regions) are included.
+    /// Definitions are available in English, German, Spanish, French, Italian and Portuguese;
+    /// every other locale falls back to English.
+    pub async fn maturity_rating_definitions(
+        &self,
+    ) -> Result<HashMap<String, MaturityRatingDefinition>> {
+        Ok(CODES
+            .into_iter()
+            .zip(localized_definitions(&self.locale()))
+            .map(|(code, (name, description))| {
+                (
+                    code.to_string(),
+                    MaturityRatingDefinition {
+                        name: name.to_string(),
+                        description: description.to_string(),
+                    },
+                )
+            })
+            .collect())
+    }
+}
+
+fn localized_definitions(locale: &Locale) -> [(&'static str, &'static str); 6] {
+    match locale {
+        Locale::de_DE => [
+            ("Alle Kinder", "Für alle Kinder geeignet."),
+            ("Für ältere Kinder", "Für Kinder ab 7 Jahren bestimmt."),
+            ("Allgemeines Publikum", "Für alle Altersgruppen geeignet."),
+            (
+                "Elterliche Begleitung empfohlen",
+                "Enthält Inhalte, die Eltern für jüngere Kinder als ungeeignet empfinden könnten.",
+            ),
+            (
+                "Eltern ausdrücklich gewarnt",
+                "Enthält Inhalte, die viele Eltern für Kinder unter 14 Jahren als ungeeignet empfinden würden.",
+            ),
+            (
+                "Nur für Erwachsene",
+                "Speziell für Erwachsene bestimmt und daher möglicherweise ungeeignet für Kinder unter 17 Jahren.",
+            ),
+        ],
+        Locale::es_419 | Locale::es_ES | Locale::es_LA => [
+            ("Todos los niños", "Apto para todos los niños."),
+            (
+                "Dirigido a niños mayores",
+                "Diseñado para niños a partir de 7 años.",
+            ),
+            ("Público general", "Apto para todas las edades."),
+            (
+                "Se sugiere orientación de los padres",
+                "Contiene material que los padres podrían considerar inapropiado para niños pequeños.",
+            ),
+            (
+                "Se advierte seriamente a los padres",
+                "Contiene material que muchos padres considerarían inapropiado para menores de 14 años.",
+            ),
+            (
+                "Solo para público adulto",
+                "Diseñado específicamente para adultos, por lo que puede ser inapropiado para menores de 17 años.",
+            ),
+        ],
+        Locale::fr_FR => [
+            ("Tous les enfants", "Convient à tous les enfants."),
+            (
+                "Destiné aux enfants plus âgés",
+                "Conçu pour les enfants de 7 ans et plus.",
+            ),
+            ("Tout public", "Convient à tous les âges."),
+            (
+                "Accord parental souhaitable",
+                "Contient des éléments que les parents pourraient juger inappropriés pour les jeunes enfants.",
+            ),
+            (
+                "Fortement déconseillé aux moins de 14 ans",
+                "Contient des éléments que de nombreux parents jugeraient inappropriés pour les enfants de moins de 14 ans.",
+            ),
+            (
+                "Public adulte uniquement",
+                "Spécifiquement destiné aux adultes et donc susceptible d'être inapproprié pour les moins de 17 ans.",
+            ),
+        ],
+        Locale::it_IT => [
+            ("Tutti i bambini", "Adatto a tutti i bambini."),
+            (
+                "Per bambini più grandi",
+                "Pensato per bambini dai 7 anni in su.",
+            ),
+            ("Per tutti", "Adatto a tutte le età."),
+            (
+                "Si consiglia la supervisione dei genitori",
+                "Contiene materiale che i genitori potrebbero ritenere inadatto ai bambini più piccoli.",
+            ),
+            (
+                "Forte cautela per i genitori",
+                "Contiene materiale che molti genitori riterrebbero inadatto ai minori di 14 anni.",
+            ),
+            (
+                "Solo per un pubblico adulto",
+                "Pensato specificamente per gli adulti e quindi potenzialmente inadatto ai minori di 17 anni.",
+            ),
+        ],
+        Locale::pt_BR | Locale::pt_PT => [
+            ("Todas as crianças", "Adequado para todas as crianças."),
+            (
+                "Direcionado a crianças mais velhas",
+                "Destinado a crianças a partir de 7 anos.",
+            ),
+            ("Público geral", "Adequado para todas as idades."),
+            (
+                "Orientação dos pais sugerida",
+                "Contém material que os pais podem considerar inadequado para crianças mais novas.",
+            ),
+            (
+                "Pais fortemente advertidos",
+                "Contém material que muitos pais considerariam inadequado para menores de 14 anos.",
+            ),
+            (
+                "Somente para público adulto",
+                "Destinado especificamente a adultos e, portanto, pode ser inadequado para menores de 17 anos.",
+            ),
+        ],
+        _ => [
+            ("All Children", "Appropriate for all children."),
+            (
+                "Directed to Older Children",
+                "Designed for children age 7 and above.",
+            ),
+            ("General Audience", "Suitable for all ages."),
+            (
+                "Parental Guidance Suggested",
+                "Contains material that parents may find unsuitable for younger children.",
+            ),
+            (
+                "Parents Strongly Cautioned",
+                "Contains material that many parents would find unsuitable for children under 14 years of age.",
+            ),
+            (
+                "Mature Audience Only",
+                "Specifically designed to be viewed by adults and therefore may be unsuitable for children under 17.",
+            ),
+        ],
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Id of a media item (series, season, episode, ...), e.g. `GRDV0019R`. Every `from_id` function
+/// (like [`crate::media::Media::from_id`]) accepts everything which can be converted into it, so
+/// `&str` and `String` can be passed directly. These conversions do not validate the id, but every
+/// `from_id` function calls [`MediaId::validate`] before sending a request, so obviously invalid
+/// ids (empty or containing characters other than ascii letters and digits) result in an
+/// [`Error::Input`] without reaching the api. Use [`MediaId::from_str`] (or [`str::parse`]) to
+/// catch them even earlier.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[serde(transparent)]
+pub struct MediaId(String);
+
+impl MediaId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Check if the id is obviously invalid, i.e. empty or containing characters other than ascii
+    /// letters and digits. Returns [`Error::Input`] if so.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.0.is_empty() {
+            return Err(Error::Input {
+                message: "media id is empty".to_string(),
+            });
+        }
+        if !self.0.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(Error::Input {
+                message: format!("media id '{}' contains invalid characters", self.0),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MediaId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = Self(s.to_string());
+        id.validate()?;
+        Ok(id)
+    }
+}
+
+impl Display for MediaId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for MediaId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for MediaId {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for MediaId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&String> for MediaId {
+    fn from(value: &String) -> Self {
+        Self(value.clone())
+    }
+}
+
+impl From<&MediaId> for MediaId {
+    fn from(value: &MediaId) -> Self {
+        value.clone()
+    }
+}
+
+impl From<MediaId> for String {
+    fn from(value: MediaId) -> Self {
+        value.0
+    }
+}
@@ -1,3 +1,11 @@
 mod image;
+mod links;
+mod maturity;
+mod media_id;
+mod search_metadata;
 
 pub use image::*;
+pub use links::*;
+pub use maturity::*;
+pub use media_id::*;
+pub use search_metadata::*;
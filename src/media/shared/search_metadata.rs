@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Ranking information of a media which was returned by a search or browse request. Can be used
+/// to order mixed results (e.g. of [`crate::search::QueryResults`]) uniformly, see
+/// [`crate::MediaCollection::search_metadata`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+pub struct SearchMetadata {
+    /// Relevance of the media for the search query. Higher is more relevant.
+    pub score: f64,
+    /// Position of the media in the results.
+    pub rank: u32,
+    /// Popularity of the media, independent of the search query.
+    pub popularity_score: f64,
+}
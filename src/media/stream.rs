@@ -1,6 +1,9 @@
 use crate::common::V2BulkResult;
 use crate::error::Error;
 use crate::{Executor, Locale, Request, Result};
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use futures_util::StreamExt;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -64,7 +67,78 @@ struct StreamVersion {
     variant: String,
 }
 
+/// Preferences which stream of an [`crate::Episode`] or [`crate::Movie`] should be resolved if
+/// multiple are available.
+#[derive(Clone, Debug, smart_default::SmartDefault)]
+pub struct StreamPreferences {
+    pub(crate) audio: Option<Locale>,
+    pub(crate) hardsub: Option<Locale>,
+    #[default(true)]
+    pub(crate) soft_subs: bool,
+}
+
+impl StreamPreferences {
+    /// Audio locale the stream should have. If the media isn't available with this audio, the
+    /// audio of the media itself is used.
+    pub fn audio(mut self, audio: Locale) -> Self {
+        self.audio = Some(audio);
+        self
+    }
+
+    /// Hardsub locale the stream should have. If not set, the stream has no hardsub. Only has an
+    /// effect if [`StreamPreferences::soft_subs`] is disabled or if the stream has no variant
+    /// without hardsub.
+    pub fn hardsub(mut self, hardsub: Locale) -> Self {
+        self.hardsub = Some(hardsub);
+        self
+    }
+
+    /// Prefer the stream variant without any hardsub and handle subtitles separately (e.g. by
+    /// writing them as separate files in [`Stream::download_to`]). If no variant without hardsub
+    /// exists, the variant with the [`StreamPreferences::hardsub`] locale is used or, if this
+    /// isn't set / doesn't exist either, any hardsubbed variant. Enabled by default.
+    pub fn soft_subs(mut self, soft_subs: bool) -> Self {
+        self.soft_subs = soft_subs;
+        self
+    }
+}
+
+/// Options which stream variants [`crate::Episode::stream_with`] / [`crate::Movie::stream_with`]
+/// should return. The stream endpoint has no parameters to filter variants, so all options are
+/// applied client-side after the stream was requested.
+#[derive(Clone, Debug, smart_default::SmartDefault)]
+pub struct StreamOptions {
+    pub(crate) hardsub: Option<Locale>,
+    #[default(true)]
+    pub(crate) include_drm: bool,
+}
+
+impl StreamOptions {
+    /// Only keep the variants with this hardsub locale and the variants without any hardsub. If
+    /// not set, the variants of all hardsub locales are kept.
+    pub fn hardsub(mut self, hardsub: Locale) -> Self {
+        self.hardsub = Some(hardsub);
+        self
+    }
+
+    /// Whether to keep drm protected variants (all `drm_*` fields of [`Variants`]). Enabled by
+    /// default.
+    pub fn include_drm(mut self, include_drm: bool) -> Self {
+        self.include_drm = include_drm;
+        self
+    }
+}
+
+/// Cache of [`crate::Episode::fresh_stream`] / [`crate::Movie::fresh_stream`]: the stream and the
+/// time it was requested.
+pub(crate) type StreamCache = Arc<std::sync::Mutex<Option<(Stream, DateTime<Utc>)>>>;
+
 /// A video stream.
+///
+/// A stream always represents the whole episode / movie; Crunchyroll doesn't split them into
+/// multiple parts on stream level, every variant is a single manifest. Media which is split into
+/// parts (like some long movies) is represented as multiple [`crate::Movie`]s in a
+/// [`crate::MovieListing`] instead, see [`crate::Movie::parts`] for its limitations.
 #[allow(dead_code)]
 #[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault, Request)]
 #[request(executor(subtitles))]
@@ -166,6 +240,89 @@ impl Stream {
         Ok(stream)
     }
 
+    /// Apply the given options to [`Stream::variants`].
+    pub(crate) fn apply_options(&mut self, options: &StreamOptions) {
+        if let Some(hardsub) = &options.hardsub {
+            self.variants
+                .retain(|locale, _| locale == hardsub || locale == &Locale::Custom("".into()))
+        }
+        if !options.include_drm {
+            self.variants.values_mut().for_each(Variants::remove_drm)
+        }
+    }
+
+    /// Download all subtitles of this stream concurrently and return their text content. A failed
+    /// download doesn't affect the other ones, its error is stored as value of the subtitle locale
+    /// instead.
+    pub async fn subtitle_texts(&self) -> HashMap<Locale, Result<String>> {
+        futures_util::stream::iter(self.subtitles.values())
+            .map(|subtitle| async move { (subtitle.locale.clone(), subtitle.text().await) })
+            .buffer_unordered(4)
+            .collect()
+            .await
+    }
+
+    /// Return the subtitle which should be shown by default when playing this stream with the
+    /// given audio locale. `preferences` are the subtitle locales the user understands, ordered by
+    /// preference (e.g. the UI language first). The decision is made as follows:
+    ///
+    /// | Audio                                   | Returned subtitle                                                        |
+    /// |-----------------------------------------|--------------------------------------------------------------------------|
+    /// | Original audio (or no versions present) | Full subtitle in the first locale of `preferences` which is available    |
+    /// | Dubbed audio                            | Forced subtitle (signs & songs only) which has the same locale as the dub |
+    ///
+    /// Crunchyroll delivers the forced subtitles of a dub as the subtitle whose locale is equal
+    /// to the dub's audio locale. [`None`] is returned if no subtitle matches.
+    pub fn recommended_subtitle(
+        &self,
+        audio: &Locale,
+        preferences: &[Locale],
+    ) -> Option<&Subtitle> {
+        let is_dub = self
+            .versions
+            .as_ref()
+            .filter(|versions| !versions.is_empty())
+            .is_some_and(|versions| {
+                !versions
+                    .iter()
+                    .any(|v| v.original && &v.audio_locale == audio)
+            });
+
+        if !is_dub {
+            preferences
+                .iter()
+                .find_map(|locale| self.subtitles.get(locale))
+        } else {
+            self.subtitles.get(audio)
+        }
+    }
+
+    /// Return when the signed urls of this stream (variants and subtitles) expire. After that,
+    /// requesting them fails and the stream must be requested again. The expiry is parsed out of
+    /// the `Policy` or `Expires` query parameter of the urls; if multiple urls have an expiry, the
+    /// earliest one is returned. [`None`] is returned if no expiry could be parsed.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.variants
+            .values()
+            .flat_map(|variants| variants.all())
+            .map(|variant| variant.url.as_str())
+            .chain(
+                self.subtitles
+                    .values()
+                    .map(|subtitle| subtitle.url.as_str()),
+            )
+            .filter_map(signed_url_expiry)
+            .min()
+    }
+
+    /// Check if the signed urls of this stream are expired. See [`Stream::expires_at`] for more
+    /// information. Always `false` if no expiry could be parsed; [`crate::Episode::fresh_stream`]
+    /// and [`crate::Movie::fresh_stream`] treat such streams as expired after a short time.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+
     pub fn available_versions(&self) -> Vec<Locale> {
         self.versions
             .clone()
@@ -222,6 +379,35 @@ impl Stream {
     }
 }
 
+/// Extract the expiry of a CloudFront signed url. Canned policies have the expiry as `Expires`
+/// query parameter, custom policies have it in the base64 encoded `Policy` query parameter.
+fn signed_url_expiry(url: &str) -> Option<DateTime<Utc>> {
+    let url = reqwest::Url::parse(url).ok()?;
+
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "Expires" => return DateTime::from_timestamp(value.parse().ok()?, 0),
+            "Policy" => {
+                // CloudFront uses a url safe base64 variant which replaces some characters
+                let policy = value.replace('-', "+").replace('_', "=").replace('~', "/");
+                let decoded = base64::engine::general_purpose::STANDARD
+                    .decode(policy)
+                    .ok()?;
+                let policy: Value = serde_json::from_slice(&decoded).ok()?;
+                return DateTime::from_timestamp(
+                    policy
+                        .pointer("/Statement/0/Condition/DateLessThan/AWS:EpochTime")?
+                        .as_i64()?,
+                    0,
+                );
+            }
+            _ => (),
+        }
+    }
+
+    None
+}
+
 /// Subtitle for streams.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
@@ -236,13 +422,38 @@ pub struct Subtitle {
 }
 
 impl Subtitle {
+    /// Write the subtitle to the given writer. A leading UTF-8 BOM (which some subtitles have) is
+    /// removed.
     pub async fn write_to(self, w: &mut impl Write) -> Result<()> {
-        let resp = self.executor.get(self.url).request_raw().await?;
-        w.write_all(resp.as_ref()).map_err(|e| Error::Input {
+        let resp = self.raw().await?;
+        w.write_all(strip_bom(&resp)).map_err(|e| Error::Input {
             message: e.to_string(),
         })?;
         Ok(())
     }
+
+    /// Return a file name for this subtitle which consists of the given base name, the subtitle
+    /// locale and the subtitle format as extension, e.g. `Show.S01E01.de-DE.ass` for the base name
+    /// `Show.S01E01`.
+    pub fn suggested_filename(&self, base: &str) -> String {
+        format!("{}.{}.{}", base, self.locale, self.format)
+    }
+
+    /// Return the text content of the subtitle. A leading UTF-8 BOM (which some subtitles have) is
+    /// removed, so it doesn't end up as stray character in the text.
+    pub async fn text(&self) -> Result<String> {
+        Ok(String::from_utf8_lossy(strip_bom(&self.raw().await?)).to_string())
+    }
+
+    /// The download is capped by [`crate::crunchyroll::CrunchyrollBuilder::max_asset_size`] and
+    /// [`crate::crunchyroll::CrunchyrollBuilder::asset_timeout`].
+    async fn raw(&self) -> Result<Vec<u8>> {
+        self.executor.get(&self.url).request_asset().await
+    }
+}
+
+fn strip_bom(raw: &[u8]) -> &[u8] {
+    raw.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(raw)
 }
 
 /// A [`Stream`] variant.
@@ -282,3 +493,46 @@ pub struct Variants {
     #[cfg(feature = "__test_strict")]
     urls: Option<crate::StrictValue>,
 }
+
+impl Variants {
+    /// Remove all drm protected variants.
+    fn remove_drm(&mut self) {
+        self.drm_adaptive_dash = None;
+        self.drm_adaptive_hls = None;
+        self.drm_download_dash = None;
+        self.drm_download_hls = None;
+        self.drm_multitrack_adaptive_hls_v2 = None;
+        self.vo_drm_adaptive_dash = None;
+        self.vo_drm_adaptive_hls = None;
+    }
+
+    /// If at least one variant is present and all of them are drm protected.
+    #[cfg(any(feature = "hls-stream", feature = "dash-stream"))]
+    pub(crate) fn is_drm_only(&self) -> bool {
+        let mut drm_free = self.clone();
+        drm_free.remove_drm();
+        self.all().next().is_some() && drm_free.all().next().is_none()
+    }
+
+    /// All variants which are present.
+    fn all(&self) -> impl Iterator<Item = &Variant> {
+        [
+            &self.adaptive_dash,
+            &self.adaptive_hls,
+            &self.download_dash,
+            &self.download_hls,
+            &self.drm_adaptive_dash,
+            &self.drm_adaptive_hls,
+            &self.drm_download_dash,
+            &self.drm_download_hls,
+            &self.drm_multitrack_adaptive_hls_v2,
+            &self.multitrack_adaptive_hls_v2,
+            &self.vo_adaptive_dash,
+            &self.vo_adaptive_hls,
+            &self.vo_drm_adaptive_dash,
+            &self.vo_drm_adaptive_hls,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
@@ -1,12 +1,15 @@
 use crate::error::Error;
-use crate::media::Stream;
+use crate::media::{Stream, StreamPreferences};
 use crate::{Executor, Locale, Request, Result};
 use serde::{Deserialize, Serialize};
 use std::borrow::BorrowMut;
 use std::fmt::Formatter;
-use std::io::Write;
+use std::io::{SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 
 /// Segment decryption key.
 #[cfg(feature = "hls-stream")]
@@ -17,6 +20,43 @@ pub type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
 #[cfg_attr(docsrs, doc(cfg(any(feature = "hls-stream", feature = "dash-stream"))))]
 pub type Aes128CbcDec = ();
 
+/// Token to cancel a running download, e.g. [`VariantData::download_to`]. Clones of a token share
+/// their state, so a clone can be passed to the download while the original is kept to cancel it
+/// (e.g. when the user presses a stop button). A cancelled download fails with
+/// [`Error::Cancelled`] and is left in a resumable state.
+///
+/// The token is checked before a download starts and between its segments; a request which is
+/// already running isn't aborted. This is also why [`VariantData::segments`] and
+/// [`VariantSegment::write_to`] don't take a token: both make exactly one request, so cancelling
+/// them would be no different from not calling them.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel all downloads which are using this token (or a clone of it).
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Return [`Error::Cancelled`] if the token is cancelled.
+    pub(crate) fn check(cancellation: Option<&CancellationToken>) -> Result<()> {
+        if cancellation.is_some_and(|c| c.is_cancelled()) {
+            return Err(Error::Cancelled {
+                message: "download was cancelled".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
 impl Stream {
     /// Returns streaming data which can be used to get the literal stream data and
     /// process it further (e.g. write them to a file which than can be played), based
@@ -188,6 +228,157 @@ impl Stream {
     pub fn streaming_hardsub_locales(&self) -> Vec<Locale> {
         self.variants.keys().cloned().collect::<Vec<Locale>>()
     }
+
+    /// Returns all qualities in which this stream is available with the given hardsub, sorted from
+    /// highest to lowest resolution. The qualities of the preferred protocol are requested first,
+    /// qualities with a resolution the preferred protocol doesn't have are added from the other
+    /// protocol, so a stream which is only available via one protocol for the hardsub still has
+    /// qualities. Every quality is labeled with the protocol it's from
+    /// ([`StreamQuality::protocol`]). A protocol is only used if its feature (`hls-stream` /
+    /// `dash-stream`) is enabled. Drm protected variants are not considered.
+    pub async fn quality_ladder(
+        &self,
+        hardsub: Option<Locale>,
+        preferred: StreamProtocol,
+    ) -> Result<Vec<StreamQuality>> {
+        let other = match preferred {
+            StreamProtocol::Hls => StreamProtocol::Dash,
+            StreamProtocol::Dash => StreamProtocol::Hls,
+        };
+
+        let mut ladder: Vec<StreamQuality> = vec![];
+        for protocol in [preferred, other] {
+            for quality in self.protocol_qualities(&hardsub, protocol).await? {
+                if !ladder.iter().any(|q| {
+                    q.resolution.width == quality.resolution.width
+                        && q.resolution.height == quality.resolution.height
+                }) {
+                    ladder.push(quality)
+                }
+            }
+        }
+        // stable sort, so the preferred protocol stays first if resolutions are equal
+        ladder.sort_by_key(|q| std::cmp::Reverse(q.resolution.width));
+        Ok(ladder)
+    }
+
+    /// Qualities of the given protocol. Empty if the protocol isn't available for the hardsub or
+    /// its feature isn't enabled.
+    async fn protocol_qualities(
+        &self,
+        hardsub: &Option<Locale>,
+        protocol: StreamProtocol,
+    ) -> Result<Vec<StreamQuality>> {
+        let keys = match hardsub {
+            Some(locale) => vec![locale.clone()],
+            None => vec![Locale::Custom("".into()), Locale::Custom(":".into())],
+        };
+        let Some(variants) = keys.iter().find_map(|key| self.variants.get(key)) else {
+            return Ok(vec![]);
+        };
+
+        match protocol {
+            #[cfg(feature = "hls-stream")]
+            StreamProtocol::Hls if variants.adaptive_hls.is_some() => {
+                Ok(VariantData::from_hls_master(
+                    self.executor.clone(),
+                    variants.adaptive_hls.as_ref().unwrap().url.clone(),
+                )
+                .await?
+                .iter()
+                .map(StreamQuality::from)
+                .collect())
+            }
+            #[cfg(feature = "dash-stream")]
+            StreamProtocol::Dash if variants.adaptive_dash.is_some() => Ok(self
+                .dash_streaming_data(hardsub.clone())
+                .await?
+                .0
+                .iter()
+                .map(StreamQuality::from)
+                .collect()),
+            _ => Ok(vec![]),
+        }
+    }
+
+    /// Download the best (highest resolution) [HLS](https://en.wikipedia.org/wiki/HTTP_Live_Streaming)
+    /// stream variant to the given path. Which variant is downloaded is decided by the given
+    /// preferences (the audio locale preference is ignored as a [`Stream`] always has exactly one
+    /// audio locale). If [`StreamPreferences::soft_subs`] is enabled (the default), the variant
+    /// without any hardsub is preferred and all subtitles are written next to the video file,
+    /// named after the video file with the subtitle locale and format as extension (e.g.
+    /// `video.en-US.ass` for `video.ts`). The video download is resumable, see
+    /// [`VariantData::download_to`] for details, also for how `cancellation` is used.
+    /// Fails with [`Error::SubscriptionRequired`] if the subscription of the current account
+    /// doesn't allow offline downloads (see [`crate::Crunchyroll::can_download_offline`]).
+    #[cfg(feature = "hls-stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hls-stream")))]
+    pub async fn download_to<P: AsRef<Path>>(
+        &self,
+        path: P,
+        preferences: StreamPreferences,
+        cancellation: Option<CancellationToken>,
+    ) -> Result<()> {
+        CancellationToken::check(cancellation.as_ref())?;
+        if !self.executor.can_download_offline().await {
+            return Err(Error::SubscriptionRequired {
+                message: "the current account isn't permitted to download offline".to_string(),
+            });
+        }
+
+        let path = path.as_ref();
+
+        let hardsub = self.preferred_hardsub(&preferences);
+        let soft_subs = preferences.soft_subs && hardsub.is_none();
+
+        let mut variants = self.hls_streaming_data(hardsub).await?;
+        variants.sort_by_key(|v| v.resolution.width);
+        let variant = variants.pop().ok_or(Error::Input {
+            message: "no stream available".to_string(),
+        })?;
+        variant.download_to(path, cancellation.clone()).await?;
+
+        if soft_subs {
+            let base = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            for subtitle in self.subtitles.values() {
+                CancellationToken::check(cancellation.as_ref())?;
+                let mut buf = vec![];
+                subtitle.clone().write_to(&mut buf).await?;
+                tokio::fs::write(path.with_file_name(subtitle.suggested_filename(&base)), buf)
+                    .await
+                    .map_err(|e| Error::Input {
+                        message: e.to_string(),
+                    })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Return the hardsub locale which should be requested to satisfy the given preferences.
+    /// [`None`] means that the variant without any hardsub should be used.
+    #[cfg(feature = "hls-stream")]
+    pub(crate) fn preferred_hardsub(&self, preferences: &StreamPreferences) -> Option<Locale> {
+        if !preferences.soft_subs {
+            return preferences.hardsub.clone();
+        }
+
+        if self.variants.contains_key(&Locale::Custom("".into())) {
+            return None;
+        }
+        // no clean stream exists, so fall back to a hardsubbed one
+        if let Some(hardsub) = &preferences.hardsub {
+            if self.variants.contains_key(hardsub) {
+                return Some(hardsub.clone());
+            }
+        }
+        let mut hardsubs = self.streaming_hardsub_locales();
+        hardsubs.sort_by_key(|l| l.to_string());
+        hardsubs.into_iter().next()
+    }
 }
 
 /// Video resolution.
@@ -212,6 +403,60 @@ impl From<m3u8_rs::Resolution> for Resolution {
     }
 }
 
+/// Protocol over which a stream is delivered.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum StreamProtocol {
+    /// [HLS](https://en.wikipedia.org/wiki/HTTP_Live_Streaming).
+    #[default]
+    Hls,
+    /// [MPEG-DASH](https://en.wikipedia.org/wiki/Dynamic_Adaptive_Streaming_over_HTTP).
+    Dash,
+}
+
+/// Overview which audios, subtitles, hardsubs and qualities a media is available in. Returned by
+/// [`crate::Episode::stream_summary`].
+#[derive(Clone, Debug, Serialize)]
+pub struct StreamSummary {
+    /// Audio locales of all versions, including the one of the media itself.
+    pub audio_locales: Vec<Locale>,
+    pub subtitle_locales: Vec<Locale>,
+    /// Locales which can be used as hardsub, e.g. in [`Stream::hls_streaming_data`].
+    pub hardsub_locales: Vec<Locale>,
+    /// If the stream is only available with drm. [`StreamSummary::qualities`] is empty then.
+    pub drm_only: bool,
+    /// Qualities of the stream without hardsub, see [`Stream::quality_ladder`].
+    pub qualities: Vec<StreamQuality>,
+}
+
+/// A quality in which a stream is available, see [`crate::Episode::quality_options`] and
+/// [`Stream::quality_ladder`].
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct StreamQuality {
+    pub resolution: Resolution,
+    pub bandwidth: u64,
+    pub fps: f64,
+    pub codecs: String,
+    /// Protocol of the stream which has this quality.
+    pub protocol: StreamProtocol,
+}
+
+impl From<&VariantData> for StreamQuality {
+    fn from(variant_data: &VariantData) -> Self {
+        Self {
+            resolution: variant_data.resolution.clone(),
+            bandwidth: variant_data.bandwidth,
+            fps: variant_data.fps,
+            codecs: variant_data.codecs.clone(),
+            protocol: match variant_data.url {
+                #[cfg(feature = "hls-stream")]
+                VariantDataUrl::Hls { .. } => StreamProtocol::Hls,
+                #[cfg(feature = "dash-stream")]
+                VariantDataUrl::MpegDash { .. } => StreamProtocol::Dash,
+            },
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 enum VariantDataUrl {
     #[cfg(feature = "hls-stream")]
@@ -229,6 +474,18 @@ enum VariantDataUrl {
     },
 }
 
+/// Progress of [`VariantData::download_to`] which is stored in a sidecar file to be able to
+/// resume a download.
+#[derive(Deserialize, Serialize)]
+struct DownloadProgress {
+    /// Number of segments the download has in total.
+    total: usize,
+    /// Number of segments which were completely written.
+    completed: usize,
+    /// Size of the target file after the last completely written segment.
+    bytes: u64,
+}
+
 /// Streaming data for a variant.
 #[allow(dead_code)]
 #[derive(Serialize, Clone, Debug, Request)]
@@ -412,6 +669,109 @@ impl VariantData {
         }
     }
 
+    /// Download all segments of this variant to the file at the given path. The download is
+    /// resumable: the progress is tracked in a sidecar file (the file path with `.progress`
+    /// appended) and if the download gets interrupted (e.g. by a network error), calling this
+    /// function again with the same path continues with the first segment which wasn't completely
+    /// written instead of starting from the beginning. The sidecar file is removed when the
+    /// download has finished. If the target file exists but has no sidecar file, it gets
+    /// overwritten.
+    /// If `cancellation` gets cancelled, the download stops after the segment which is currently
+    /// written and [`Error::Cancelled`] is returned. The download can be resumed later like an
+    /// interrupted one. If it's already cancelled when calling this function, it fails before
+    /// anything else is done (not even the target file is created).
+    /// Fails with [`Error::SubscriptionRequired`] if the subscription of the current account
+    /// doesn't allow offline downloads (see [`crate::Crunchyroll::can_download_offline`]).
+    pub async fn download_to<P: AsRef<Path>>(
+        &self,
+        path: P,
+        cancellation: Option<CancellationToken>,
+    ) -> Result<()> {
+        CancellationToken::check(cancellation.as_ref())?;
+        if !self.executor.can_download_offline().await {
+            return Err(Error::SubscriptionRequired {
+                message: "the current account isn't permitted to download offline".to_string(),
+            });
+        }
+
+        let path = path.as_ref();
+        let mut progress_path = path.as_os_str().to_os_string();
+        progress_path.push(".progress");
+        let progress_path = PathBuf::from(progress_path);
+
+        let segments = self.segments().await?;
+
+        let mut progress = match tokio::fs::read(&progress_path).await {
+            Ok(raw) => serde_json::from_slice::<DownloadProgress>(&raw).ok(),
+            Err(_) => None,
+        };
+        // a different number of segments means that the progress file belongs to another download,
+        // a smaller file that the target file was modified after the progress was written
+        if let Some(p) = &progress {
+            let target_len = tokio::fs::metadata(path).await.map(|m| m.len());
+            if p.total != segments.len() || !target_len.is_ok_and(|len| len >= p.bytes) {
+                progress = None
+            }
+        }
+        let mut progress = progress.unwrap_or(DownloadProgress {
+            total: segments.len(),
+            completed: 0,
+            bytes: 0,
+        });
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(progress.completed == 0)
+            .open(path)
+            .await
+            .map_err(|e| Error::Input {
+                message: e.to_string(),
+            })?;
+        // discard everything after the last completely written segment
+        file.set_len(progress.bytes)
+            .await
+            .map_err(|e| Error::Input {
+                message: e.to_string(),
+            })?;
+        file.seek(SeekFrom::End(0))
+            .await
+            .map_err(|e| Error::Input {
+                message: e.to_string(),
+            })?;
+
+        for segment in &segments[progress.completed..] {
+            CancellationToken::check(cancellation.as_ref())?;
+            let mut buf = vec![];
+            segment.write_to(&mut buf).await?;
+            file.write_all(&buf).await.map_err(|e| Error::Input {
+                message: e.to_string(),
+            })?;
+            file.flush().await.map_err(|e| Error::Input {
+                message: e.to_string(),
+            })?;
+
+            progress.completed += 1;
+            progress.bytes += buf.len() as u64;
+            tokio::fs::write(&progress_path, serde_json::to_vec(&progress)?)
+                .await
+                .map_err(|e| Error::Input {
+                    message: e.to_string(),
+                })?;
+        }
+
+        // the sidecar file was never written if the variant has no segments
+        if let Err(e) = tokio::fs::remove_file(&progress_path).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(Error::Input {
+                    message: e.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     #[cfg(feature = "hls-stream")]
     async fn hls_segments(&self) -> Result<Vec<VariantSegment>> {
         use aes::cipher::KeyIvInit;
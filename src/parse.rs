@@ -4,7 +4,7 @@ use regex::Regex;
 
 /// Types of Crunchyroll urls, pointing to media.
 #[cfg_attr(docsrs, doc(cfg(feature = "parse")))]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum UrlType {
     /// The parsed url points to a series. Use [`crate::Series::from_id`] with the value of this
     /// field to get a usable struct out of it.
@@ -63,3 +63,19 @@ pub fn parse_url<S: AsRef<str>>(url: S) -> Option<UrlType> {
         None
     }
 }
+
+/// Build a Crunchyroll url out of a [`UrlType`]. This is the inverse of [`parse_url`], so
+/// `parse_url(build_url(&url_type))` always returns `url_type` again. The built url doesn't contain
+/// the title slug Crunchyroll usually puts at the end of their urls (use the `url` method of the
+/// media structs, e.g. [`crate::Series::url`], if you need it) but is still valid.
+#[cfg_attr(docsrs, doc(cfg(feature = "parse")))]
+pub fn build_url(url_type: &UrlType) -> String {
+    let path = match url_type {
+        UrlType::Series(id) => format!("series/{id}"),
+        UrlType::MovieListing(id) => format!("movie_listing/{id}"),
+        UrlType::EpisodeOrMovie(id) => format!("watch/{id}"),
+        UrlType::MusicVideo(id) => format!("watch/musicvideo/{id}"),
+        UrlType::Concert(id) => format!("watch/concert/{id}"),
+    };
+    format!("https://www.crunchyroll.com/{path}/")
+}
@@ -60,6 +60,14 @@ enum_values! {
     }
 }
 
+enum_values! {
+    /// Reasons to report a comment with [`Comment::report`].
+    pub enum CommentReportReason {
+        Spoiler = "spoiler"
+        Inappropriate = "inappropriate"
+    }
+}
+
 /// Comment about a episode or movie.
 #[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault, Request)]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
@@ -112,7 +120,7 @@ impl Comment {
                     let result: BulkResult<Comment> = options
                         .executor
                         .get(endpoint)
-                        .query(&[("page", options.page), ("page_size", options.page_size)])
+                        .query(&options.page_query())
                         .apply_locale_query()
                         .request()
                         .await?;
@@ -189,6 +197,58 @@ impl Comment {
         Ok(())
     }
 
+    /// Like this comment. Does nothing if you've already liked it.
+    pub async fn like(&mut self) -> Result<()> {
+        if self.user_flags.contains(&CommentFlag::Like) {
+            return Ok(());
+        }
+        self.flag(CommentFlag::Like, true).await
+    }
+
+    /// Remove your like from this comment. Does nothing if you haven't liked it.
+    pub async fn unlike(&mut self) -> Result<()> {
+        if !self.user_flags.contains(&CommentFlag::Like) {
+            return Ok(());
+        }
+        self.flag(CommentFlag::Like, false).await
+    }
+
+    /// Report this comment. Returns [`crate::error::Error::Input`] if `reason` is
+    /// [`CommentReportReason::Custom`] and [`crate::error::Error::AlreadyReported`] if you've
+    /// already reported the comment for this reason, either according to [`Comment::user_flags`]
+    /// or because the api rejects the report as duplicate (e.g. if [`Comment::user_flags`] is
+    /// outdated). Use [`Comment::flag`] to take back a report.
+    pub async fn report(&mut self, reason: CommentReportReason) -> Result<()> {
+        let flag = match reason {
+            CommentReportReason::Spoiler => CommentFlag::Spoiler,
+            CommentReportReason::Inappropriate => CommentFlag::Inappropriate,
+            CommentReportReason::Custom(reason) => {
+                return Err(crate::error::Error::Input {
+                    message: format!("invalid comment report reason '{reason}'"),
+                })
+            }
+        };
+        let already_reported = crate::error::Error::AlreadyReported {
+            message: format!("comment {} is already reported as {flag}", self.comment_id),
+        };
+        if self.user_flags.contains(&flag) {
+            return Err(already_reported);
+        }
+        match self.flag(flag.clone(), true).await {
+            // the api rejects votes which already exist with a conflict status or an error code
+            // mentioning that the vote already exists
+            Err(crate::error::Error::Request {
+                message, status, ..
+            }) if status == Some(reqwest::StatusCode::CONFLICT)
+                || message.to_lowercase().contains("already") =>
+            {
+                self.user_flags.push(flag);
+                Err(already_reported)
+            }
+            result => result,
+        }
+    }
+
     /// Edit this comment. You **must** be the author of it so perform this request. See
     /// [`Comment::is_owner`] if the comment is written by you. If you use this function, its
     /// argument has always be the opposite of [`Comment::is_spoiler`], else a error will occur.
@@ -254,6 +314,10 @@ macro_rules! impl_comment {
     ($($s:path)*) => {
         $(
             impl $s {
+                /// Return the comments / discussion about this media. Only top level comments are
+                /// returned, use [`Comment::replies`] to get the replies to a comment. Crunchyroll
+                /// only supports sorting by [`CommentSortType`], filtering (e.g. hiding spoilers)
+                /// must be done manually via the fields of [`Comment`].
                 pub fn comments(&self, options: CommentsOptions) -> Pagination<Comment> {
                     Pagination::new(|options| {
                         async move {
@@ -262,7 +326,7 @@ macro_rules! impl_comment {
                                 .executor
                                 .get(endpoint)
                                 .query(&options.query)
-                                .query(&[("page", options.page), ("page_size", options.page_size)])
+                                .query(&options.page_query())
                                 .apply_locale_query()
                                 .request()
                                 .await?;
@@ -272,8 +336,10 @@ macro_rules! impl_comment {
                     }, self.executor.clone(), Some(options.into_query()), Some(vec![("id", self.id.clone())]))
                 }
 
+                /// Write a comment about this media. The comment is written in the locale which is
+                /// currently set ([`crate::Crunchyroll::locale`]).
                 pub async fn comment<S: AsRef<str>>(&self, message: S, is_spoiler: bool) -> Result<Comment> {
-                    create_comment(&self.executor, &self.id, message.as_ref().to_string(), is_spoiler, &self.executor.details.locale, None).await
+                    create_comment(&self.executor, &self.id, message.as_ref().to_string(), is_spoiler, &self.executor.locale(), None).await
                 }
             }
         )*
@@ -61,6 +61,50 @@ pub struct Rating {
     pub rating: Option<RatingStar>,
 }
 
+impl Rating {
+    /// Number of user ratings per star, from one star (index 0) to five stars (index 4). Counts
+    /// above 1000 are only delivered in a shortened form (e.g. `1.7K`) by Crunchyroll, these are
+    /// calculated from the [`RatingStarDetails::percentage`] and [`Rating::total`] instead and thus
+    /// are not always exact.
+    pub fn distribution(&self) -> [u32; 5] {
+        [
+            &self.one_star,
+            &self.two_stars,
+            &self.three_stars,
+            &self.four_stars,
+            &self.five_stars,
+        ]
+        .map(|star| star.count(self.total))
+    }
+
+    /// Total number of user ratings. Same as [`Rating::total`] (the field).
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+}
+
+impl RatingStarDetails {
+    fn count(&self, total: u32) -> u32 {
+        let displayed = self.displayed.parse::<f64>().unwrap_or_default();
+        match self.unit.as_str() {
+            "" => displayed as u32,
+            unit => match self.percentage {
+                Some(percentage) if total > 0 => {
+                    (total as f64 * percentage as f64 / 100.0).round() as u32
+                }
+                _ => {
+                    let multiplier = match unit {
+                        "K" => 1_000.0,
+                        "M" => 1_000_000.0,
+                        _ => 1.0,
+                    };
+                    (displayed * multiplier).round() as u32
+                }
+            },
+        }
+    }
+}
+
 /// Ratings for a review a user has made about a series or movie listing.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
@@ -139,7 +183,7 @@ impl Review {
         self.executor
             .put(endpoint)
             .json(&json!({ "rating": rating }))
-            .request()
+            .request::<()>()
             .await?;
         self.ratings.helpful = Some(helpful);
         Ok(())
@@ -188,7 +232,7 @@ impl SelfReview {
         let endpoint = format!(
             "https://www.crunchyroll.com/content-reviews/v2/{}/user/{}/rating/{}/{}",
             self.executor.details.account_id.clone()?,
-            self.executor.details.locale,
+            self.executor.locale(),
             self.endpoint,
             self.review.id
         );
@@ -207,7 +251,7 @@ impl SelfReview {
         let endpoint = format!(
             "https://www.crunchyroll.com/content-reviews/v2/{}/user/{}/rating/{}/{}",
             self.executor.details.account_id.clone()?,
-            self.executor.details.locale,
+            self.executor.locale(),
             self.endpoint,
             self.review.id
         );
@@ -268,14 +312,14 @@ macro_rules! impl_rating {
                             let result: BulkResult<Review> = options
                                 .executor
                                 .get(endpoint)
-                                .query(&[("page", options.page), ("page_size", options.page_size)])
+                                .query(&options.page_query())
                                 .query(&options.query)
                                 .request()
                                 .await?;
                             Ok(result.into())
                         }
                         .boxed()
-                    }, self.executor.clone(), Some(options.into_query()), Some(vec![("locale", self.executor.details.locale.to_string()), ("account_id", self.executor.details.account_id.clone()?), ("id", self.id.clone())])))
+                    }, self.executor.clone(), Some(options.into_query()), Some(vec![("locale", self.executor.locale().to_string()), ("account_id", self.executor.details.account_id.clone()?), ("id", self.id.clone())])))
                 }
 
                 pub async fn rate(&self, stars: RatingStar) -> Result<Rating> {
@@ -303,7 +347,7 @@ macro_rules! impl_rating {
                 pub async fn self_review(&self) -> Result<SelfReview> {
                     let endpoint = format!(
                         "https://www.crunchyroll.com/content-reviews/v2/{}/user/{}/rating/{}/{}",
-                        self.executor.details.account_id.clone()?, self.executor.details.locale, $endpoint, self.id
+                        self.executor.details.account_id.clone()?, self.executor.locale(), $endpoint, self.id
                     );
                     let mut self_review: SelfReview = self.executor.get(endpoint).request().await?;
                     self_review.endpoint = stringify!($endpoint).to_string();
@@ -1,7 +1,7 @@
 //! Browse and search related types.
 
 mod browse {
-    use crate::categories::Category;
+    use crate::categories::{Category, CategoryInformation};
     use crate::common::{Pagination, PaginationBulkResultMeta, V2BulkResult};
     use crate::media::MediaType;
     use crate::{enum_values, options, Crunchyroll, Locale, MediaCollection, Request, Result};
@@ -66,7 +66,44 @@ mod browse {
         media_type(MediaType, "type") = None
     }
 
+    /// Values which can be used to filter / sort browse results via [`BrowseOptions`]. Returned by
+    /// [`Crunchyroll::browse_facets`].
+    #[derive(Clone, Debug, Default, Serialize)]
+    pub struct BrowseFacets {
+        /// All categories which can be used in [`BrowseOptions::categories`].
+        pub categories: Vec<CategoryInformation>,
+        /// All simulcast seasons which can be used in [`BrowseOptions::simulcast_season`].
+        pub simulcast_seasons: Vec<SimulcastSeason>,
+        /// All sort types which can be used in [`BrowseOptions::sort`].
+        pub sort_types: Vec<BrowseSortType>,
+        /// All media types which can be used in [`BrowseOptions::media_type`].
+        pub media_types: Vec<MediaType>,
+    }
+
     impl Crunchyroll {
+        /// Returns all values which can be used to filter / sort browse results. The categories
+        /// and simulcast seasons are requested from the api (and are localized with the locale of
+        /// this [`Crunchyroll`] instance), the sort and media types are static as the api doesn't
+        /// provide them.
+        pub async fn browse_facets(&self) -> Result<BrowseFacets> {
+            let (categories, simulcast_seasons) = futures_util::future::try_join(
+                self.categories(),
+                self.simulcast_seasons(self.locale()),
+            )
+            .await?;
+
+            Ok(BrowseFacets {
+                categories,
+                simulcast_seasons,
+                sort_types: vec![
+                    BrowseSortType::Popularity,
+                    BrowseSortType::NewlyAdded,
+                    BrowseSortType::Alphabetical,
+                ],
+                media_types: vec![MediaType::Series, MediaType::Movie],
+            })
+        }
+
         /// Browses the crunchyroll catalog filtered by the specified options and returns all found
         /// series and movies.
         pub fn browse(&self, options: BrowseOptions) -> Pagination<MediaCollection> {
@@ -80,7 +117,7 @@ mod browse {
                                 .clone()
                                 .get(endpoint)
                                 .query(&options.query)
-                                .query(&[("n", options.page_size), ("start", options.start)])
+                                .query(&options.offset_query())
                                 .apply_locale_query()
                                 .apply_preferred_audio_locale_query()
                                 .request()
@@ -95,6 +132,50 @@ mod browse {
             )
         }
 
+        /// Browses the crunchyroll catalog sorted by popularity, the most popular media first. This
+        /// is a shortcut for [`Crunchyroll::browse`] with [`BrowseSortType::Popularity`], every
+        /// other option is kept. The popularity of a result is available via
+        /// [`MediaCollection::search_metadata`] (see
+        /// [`crate::media::SearchMetadata::popularity_score`]). Crunchyroll has no distinct
+        /// "trending" endpoint, popularity is the closest to it.
+        pub fn popular(&self, options: BrowseOptions) -> Pagination<MediaCollection> {
+            self.browse(options.sort(BrowseSortType::Popularity))
+        }
+
+        /// Like [`Crunchyroll::browse`] but returns series, movie listings and music (music videos
+        /// and concerts) mixed in one pagination, in the order Crunchyroll ranked them. Use
+        /// [`MediaCollection::kind`] to distinguish the results. [`BrowseOptions::media_type`] is
+        /// ignored, all other options are applied.
+        pub fn browse_all_types(&self, options: BrowseOptions) -> Pagination<MediaCollection> {
+            let mut query = options.into_query();
+            query.retain(|(key, _)| key != "type");
+            query.push(("type".to_string(), "series,movie_listing,music".to_string()));
+
+            Pagination::new(
+                |options| {
+                    async move {
+                        let endpoint = "https://www.crunchyroll.com/content/v2/discover/browse";
+                        let result: V2BulkResult<MediaCollection, PaginationBulkResultMeta> =
+                            options
+                                .executor
+                                .clone()
+                                .get(endpoint)
+                                .query(&options.query)
+                                .query(&options.offset_query())
+                                .apply_locale_query()
+                                .apply_preferred_audio_locale_query()
+                                .request()
+                                .await?;
+                        Ok(result.into())
+                    }
+                    .boxed()
+                },
+                self.executor.clone(),
+                Some(query),
+                None,
+            )
+        }
+
         /// Returns all simulcast seasons. The locale specified which language the localization /
         /// human readable name ([`SimulcastSeasonLocalization::title`]) has.
         pub async fn simulcast_seasons(&self, locale: Locale) -> Result<Vec<SimulcastSeason>> {
@@ -112,9 +193,11 @@ mod browse {
 
 mod query {
     use crate::common::{Pagination, V2BulkResult, V2TypeBulkResult};
+    use crate::error::Error;
     use crate::media::{Episode, MovieListing, Series};
-    use crate::{Crunchyroll, MediaCollection, MusicVideo};
+    use crate::{Crunchyroll, MediaCollection, MusicVideo, Result};
     use futures_util::FutureExt;
+    use std::collections::BTreeMap;
 
     /// Results when querying Crunchyroll.
     pub struct QueryResults {
@@ -125,8 +208,24 @@ mod query {
         pub music: Pagination<MusicVideo>,
     }
 
+    /// Get the search query from the pagination extras. Empty (or whitespace only) queries are
+    /// rejected with an [`Error::Input`] before any request is made as Crunchyroll just returns
+    /// empty results for them.
+    fn search_query<'a>(extra: &'a BTreeMap<&'static str, String>) -> Result<&'a String> {
+        let query = extra.get("q").unwrap();
+        if query.trim().is_empty() {
+            return Err(Error::Input {
+                message: "search query must not be empty".to_string(),
+            });
+        }
+        Ok(query)
+    }
+
     impl Crunchyroll {
-        /// Search the Crunchyroll catalog by a given query / string.
+        /// Search the Crunchyroll catalog by a given query / string. The query gets url encoded, so
+        /// it can contain spaces, special characters (like `&` or `:`) and unicode. If the query is
+        /// empty or consists only of whitespace, the result paginations yield an [`Error::Input`]
+        /// instead of sending a request.
         pub fn query<S: AsRef<str>>(&self, query: S) -> QueryResults {
             QueryResults {
                 top_results: Pagination::new(
@@ -136,9 +235,9 @@ mod query {
                             let result: V2BulkResult<V2TypeBulkResult<MediaCollection>> = options
                                 .executor
                                 .get(endpoint)
-                                .query(&[("q", options.extra.get("q").unwrap())])
+                                .query(&[("q", search_query(&options.extra)?)])
                                 .query(&[("type", "top_results")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
+                                .query(&options.offset_query())
                                 .apply_locale_query()
                                 .request()
                                 .await?;
@@ -162,9 +261,9 @@ mod query {
                             let result: V2BulkResult<V2TypeBulkResult<Series>> = options
                                 .executor
                                 .get(endpoint)
-                                .query(&[("q", options.extra.get("q").unwrap())])
+                                .query(&[("q", search_query(&options.extra)?)])
                                 .query(&[("type", "series")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
+                                .query(&options.offset_query())
                                 .apply_locale_query()
                                 .request()
                                 .await?;
@@ -188,9 +287,9 @@ mod query {
                             let result: V2BulkResult<V2TypeBulkResult<MovieListing>> = options
                                 .executor
                                 .get(endpoint)
-                                .query(&[("q", options.extra.get("q").unwrap())])
+                                .query(&[("q", search_query(&options.extra)?)])
                                 .query(&[("type", "movie_listing")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
+                                .query(&options.offset_query())
                                 .apply_locale_query()
                                 .request()
                                 .await?;
@@ -214,9 +313,9 @@ mod query {
                             let result: V2BulkResult<V2TypeBulkResult<Episode>> = options
                                 .executor
                                 .get(endpoint)
-                                .query(&[("q", options.extra.get("q").unwrap())])
+                                .query(&[("q", search_query(&options.extra)?)])
                                 .query(&[("type", "episode")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
+                                .query(&options.offset_query())
                                 .apply_locale_query()
                                 .request()
                                 .await?;
@@ -240,9 +339,9 @@ mod query {
                             let result: V2BulkResult<V2TypeBulkResult<MusicVideo>> = options
                                 .executor
                                 .get(endpoint)
-                                .query(&[("q", options.extra.get("q").unwrap())])
+                                .query(&[("q", search_query(&options.extra)?)])
                                 .query(&[("type", "music")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
+                                .query(&options.offset_query())
                                 .apply_locale_query()
                                 .request()
                                 .await?;
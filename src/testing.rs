@@ -0,0 +1,198 @@
+//! Fixtures to construct media without network access.
+//!
+//! Code which consumes media (e.g. [`Series`] or [`MediaCollection`]) can't be tested without a
+//! Crunchyroll account otherwise. The fixtures are shaped like the panels the api returns, but
+//! they only contain the most commonly used fields; every other field has its default value.
+//! Functions which are making requests can't be used on media constructed from fixtures.
+//!
+//! ```
+//! use crunchyroll_rs::testing::MediaFixture;
+//! use crunchyroll_rs::Episode;
+//! use serde_json::json;
+//!
+//! let episode = Episode::test_fixture(json!({ "title": "Custom title" })).unwrap();
+//! assert_eq!(episode.title, "Custom title");
+//! ```
+
+use crate::media::Media;
+use crate::{
+    Crunchyroll, Episode, Locale, MediaCollection, Movie, MovieListing, Result, Season, Series,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Sample series panel.
+pub const SERIES: &str = r#"{
+    "id": "GY8VEQ95Y",
+    "type": "series",
+    "channel_id": "crunchyroll",
+    "title": "DARLING in the FRANXX",
+    "slug_title": "darling-in-the-franxx",
+    "description": "In the distant future, humanity has been driven to near-extinction.",
+    "series_metadata": {
+        "season_count": 1,
+        "episode_count": 24,
+        "series_launch_year": 2018,
+        "is_simulcast": false,
+        "is_dubbed": true,
+        "is_subbed": true,
+        "audio_locales": ["ja-JP", "en-US"],
+        "subtitle_locales": ["en-US", "de-DE"],
+        "maturity_ratings": ["TV-14"],
+        "is_mature": false
+    }
+}"#;
+
+/// Sample season of [`SERIES`].
+pub const SEASON: &str = r#"{
+    "id": "GRZXCMN1W",
+    "type": "season",
+    "channel_id": "crunchyroll",
+    "series_id": "GY8VEQ95Y",
+    "title": "DARLING in the FRANXX",
+    "slug_title": "darling-in-the-franxx",
+    "description": "In the distant future, humanity has been driven to near-extinction.",
+    "season_number": 1,
+    "season_sequence_number": 1,
+    "number_of_episodes": 24,
+    "is_complete": true,
+    "audio_locales": ["ja-JP"],
+    "subtitle_locales": ["en-US", "de-DE"]
+}"#;
+
+/// Sample (first) episode of [`SEASON`].
+pub const EPISODE: &str = r#"{
+    "id": "GRDKJZ81Y",
+    "type": "episode",
+    "channel_id": "crunchyroll",
+    "title": "Alone and Lonesome",
+    "slug_title": "alone-and-lonesome",
+    "description": "Hiro meets a mysterious girl with horns.",
+    "episode_metadata": {
+        "series_id": "GY8VEQ95Y",
+        "series_title": "DARLING in the FRANXX",
+        "season_id": "GRZXCMN1W",
+        "season_title": "DARLING in the FRANXX",
+        "season_number": 1,
+        "season_sequence_number": 1,
+        "episode": "1",
+        "episode_number": 1,
+        "sequence_number": 1,
+        "audio_locale": "ja-JP",
+        "subtitle_locales": ["en-US", "de-DE"],
+        "duration_ms": 1420000,
+        "episode_air_date": "2018-01-13T15:30:00Z",
+        "free_available_date": "2018-01-20T15:30:00Z",
+        "premium_available_date": "2018-01-13T15:30:00Z",
+        "is_dubbed": false,
+        "is_subbed": true,
+        "is_premium_only": false
+    }
+}"#;
+
+/// Sample movie listing panel.
+pub const MOVIE_LISTING: &str = r#"{
+    "id": "G6NQ5DWZ6",
+    "type": "movie_listing",
+    "channel_id": "crunchyroll",
+    "title": "Sample Movie",
+    "slug_title": "sample-movie",
+    "description": "A sample movie listing.",
+    "movie_listing_metadata": {
+        "movie_release_year": 2019,
+        "is_dubbed": false,
+        "is_subbed": true,
+        "is_premium_only": true
+    }
+}"#;
+
+/// Sample movie of [`MOVIE_LISTING`].
+pub const MOVIE: &str = r#"{
+    "id": "G25FVD45Q",
+    "type": "movie",
+    "channel_id": "crunchyroll",
+    "title": "Sample Movie",
+    "slug_title": "sample-movie",
+    "description": "A sample movie.",
+    "movie_metadata": {
+        "movie_listing_id": "G6NQ5DWZ6",
+        "movie_listing_title": "Sample Movie",
+        "duration_ms": 6300000,
+        "is_premium_only": true
+    }
+}"#;
+
+/// Construct media from the fixtures of this module.
+pub trait MediaFixture: Media + DeserializeOwned + Sized {
+    /// The fixture the media is constructed from.
+    const FIXTURE: &'static str;
+
+    /// Construct the media from [`MediaFixture::FIXTURE`]. The fields of `overrides` (must be a
+    /// json object or null) replace the top level fields of the fixture, fields of a
+    /// `*_metadata` block can be overridden on the top level too.
+    fn test_fixture(overrides: Value) -> Result<Self> {
+        Ok(serde_json::from_value(merge_fixture(
+            Self::FIXTURE,
+            overrides,
+        )?)?)
+    }
+}
+
+macro_rules! impl_media_fixture {
+    ($($media:ident = $fixture:ident)*) => {
+        $(
+            impl MediaFixture for $media {
+                const FIXTURE: &'static str = $fixture;
+            }
+        )*
+    }
+}
+
+impl_media_fixture! {
+    Series = SERIES
+    Season = SEASON
+    Episode = EPISODE
+    MovieListing = MOVIE_LISTING
+    Movie = MOVIE
+}
+
+/// Construct a [`MediaCollection`] from any json fixture, e.g. one of the constants in this module
+/// or a response you've recorded yourself.
+pub fn media_collection_fixture(fixture: &str) -> Result<MediaCollection> {
+    Ok(serde_json::from_str(fixture)?)
+}
+
+/// Construct a [`Crunchyroll`] instance which isn't logged in, with `locale` as its locale. Only
+/// functions which don't make any request (e.g. [`Crunchyroll::maturity_rating_definitions`]) can
+/// be used with it.
+pub fn crunchyroll_fixture(locale: Locale) -> Crunchyroll {
+    let crunchyroll = Crunchyroll::from_executor(Default::default());
+    crunchyroll.set_locale(locale);
+    crunchyroll
+}
+
+fn merge_fixture(fixture: &str, overrides: Value) -> Result<Value> {
+    let mut value: Value = serde_json::from_str(fixture)?;
+    let object = value.as_object_mut().unwrap();
+    match overrides {
+        Value::Object(overrides) => {
+            for (key, override_value) in overrides {
+                // remove the field from the metadata block, otherwise the metadata value would
+                // replace the override when the block gets flattened while deserializing
+                for (_, metadata) in object.iter_mut().filter(|(k, _)| k.ends_with("_metadata")) {
+                    if let Some(metadata) = metadata.as_object_mut() {
+                        metadata.remove(&key);
+                    }
+                }
+                object.insert(key, override_value);
+            }
+        }
+        Value::Null => (),
+        _ => {
+            return Err(crate::error::Error::Input {
+                message: "fixture overrides must be a json object".to_string(),
+            })
+        }
+    }
+    Ok(value)
+}
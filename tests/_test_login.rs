@@ -74,8 +74,24 @@ async fn login_anonymously() {
 
     assert_result!(crunchy);
     assert_eq!(crunchy.as_ref().unwrap().premium().await, false);
+    assert_eq!(
+        crunchy.as_ref().unwrap().can_download_offline().await,
+        false
+    );
 
     if !utils::session::has_session() {
         utils::session::set_session(crunchy.unwrap()).await.unwrap()
     }
 }
+
+#[tokio::test]
+async fn login_anonymously_with_extra_query() {
+    let crunchy = Crunchyroll::builder()
+        .extra_query(vec![("ratings".to_string(), "true".to_string())])
+        .login_anonymously()
+        .await;
+
+    assert_result!(crunchy);
+    let categories = crunchy.unwrap().categories().await;
+    assert_result!(categories)
+}
@@ -51,3 +51,19 @@ async fn all_wallpapers() {
 
     assert_result!(Wallpaper::all_wallpapers(crunchy).await)
 }
+
+#[tokio::test]
+async fn cms_info() {
+    let crunchy = SESSION.get().await.unwrap();
+    let cms_info = crunchy.cms_info();
+
+    assert!(!cms_info.bucket.is_empty());
+    assert!(!cms_info.signature.is_empty());
+    assert!(cms_info.expires > chrono::Utc::now())
+}
+
+#[tokio::test]
+async fn subscriptions() {
+    let crunchy = SESSION.get().await.unwrap();
+    assert_result!(crunchy.subscriptions().await)
+}
@@ -14,3 +14,12 @@ async fn sub_categories() {
     let categories = crunchy.categories().await.unwrap();
     assert_result!(categories.first().unwrap().sub_categories().await)
 }
+
+#[tokio::test]
+async fn category_name_and_image() {
+    let crunchy = SESSION.get().await.unwrap();
+    for category in crunchy.categories().await.unwrap() {
+        assert!(!category.name().is_empty());
+        assert!(category.background_image().is_some())
+    }
+}
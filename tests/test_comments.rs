@@ -1,5 +1,8 @@
 use crate::utils::{Store, SESSION};
-use crunchyroll_rs::rating::{Comment, CommentFlag, CommentsOptions};
+use crunchyroll_rs::error::Error;
+use crunchyroll_rs::rating::{
+    Comment, CommentFlag, CommentReportReason, CommentSortType, CommentsOptions,
+};
 use crunchyroll_rs::Episode;
 use futures_util::StreamExt;
 
@@ -51,3 +54,54 @@ async fn comment_flag() {
             .await
     );
 }
+
+#[tokio::test]
+async fn comments_newest() {
+    let crunchy = SESSION.get().await.unwrap();
+    let episode: Episode = crunchy.media_from_id("GRDKJZ81Y").await.unwrap();
+
+    let comments: Vec<Comment> = episode
+        .comments(CommentsOptions::default().sort(CommentSortType::Newest))
+        .take(10)
+        .map(|comment| comment.unwrap())
+        .collect()
+        .await;
+    assert!(comments
+        .windows(2)
+        .all(|window| window[0].created >= window[1].created))
+}
+
+#[tokio::test]
+async fn comment_like_unlike() {
+    let mut comment = COMMENT.get().await.unwrap().clone();
+
+    let result = comment.like().await;
+    assert_result!(result);
+    assert!(comment.user_flags.contains(&CommentFlag::Like));
+    let result = comment.unlike().await;
+    assert_result!(result);
+    assert!(!comment.user_flags.contains(&CommentFlag::Like))
+}
+
+#[tokio::test]
+async fn comment_report_invalid_reason() {
+    let mut comment = COMMENT.get().await.unwrap().clone();
+
+    assert!(comment
+        .report(CommentReportReason::Custom("invalid".to_string()))
+        .await
+        .is_err())
+}
+
+#[tokio::test]
+async fn comment_report_already_reported() {
+    let mut comment = COMMENT.get().await.unwrap().clone();
+    if !comment.user_flags.contains(&CommentFlag::Spoiler) {
+        comment.user_flags.push(CommentFlag::Spoiler)
+    }
+
+    assert!(matches!(
+        comment.report(CommentReportReason::Spoiler).await,
+        Err(Error::AlreadyReported { .. })
+    ))
+}
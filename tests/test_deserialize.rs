@@ -0,0 +1,345 @@
+// the partial json used here would be rejected when testing with strict deserialization
+#![cfg(not(feature = "__test_strict"))]
+
+use crunchyroll_rs::account::{Subscription, SubscriptionStatus};
+use crunchyroll_rs::media::{Channel, MediaKind, MusicKind};
+use crunchyroll_rs::rating::Rating;
+use crunchyroll_rs::{Episode, MediaCollection, Season, Series};
+use serde_json::json;
+
+#[test]
+fn series_content_descriptors() {
+    let series: Series = serde_json::from_value(json!({
+        "id": "GY8VEQ95Y",
+        "content_descriptors": ["Violence", "Profanity"]
+    }))
+    .unwrap();
+    assert_eq!(series.content_descriptors, vec!["Violence", "Profanity"]);
+
+    let series: Series = serde_json::from_value(json!({ "id": "GY8VEQ95Y" })).unwrap();
+    assert!(series.content_descriptors.is_empty())
+}
+
+#[test]
+fn panel_with_unknown_fields() {
+    let panel: MediaCollection = serde_json::from_value(json!({
+        "id": "GY8VEQ95Y",
+        "title": "Darling in the FranXX",
+        "type": "series",
+        "some_new_field": { "nested": [1, 2, 3] },
+        "series_metadata": {
+            "episode_count": 24,
+            "another_new_field": true
+        }
+    }))
+    .unwrap();
+    let MediaCollection::Series(series) = panel else {
+        panic!("panel is not a series")
+    };
+    assert_eq!(series.id, "GY8VEQ95Y");
+    assert_eq!(series.episode_count, 24);
+
+    let panel: MediaCollection = serde_json::from_value(json!({
+        "id": "G4VUQ588P",
+        "type": "episode",
+        "some_new_field": "value",
+        "episode_metadata": {
+            "sequence_number": 1.0,
+            "another_new_field": null
+        }
+    }))
+    .unwrap();
+    assert!(matches!(panel, MediaCollection::Episode(_)))
+}
+
+#[test]
+fn episode_channel_and_external_id() {
+    let episode: Episode = serde_json::from_value(json!({
+        "id": "G4VUQ588P",
+        "channel_id": "crunchyroll",
+        "external_id": "EPI.907016"
+    }))
+    .unwrap();
+    assert_eq!(episode.channel(), Channel::Crunchyroll);
+    assert_eq!(episode.external_id.as_deref(), Some("EPI.907016"));
+
+    let episode: Episode = serde_json::from_value(json!({
+        "id": "G4VUQ588P",
+        "channel_id": "partner"
+    }))
+    .unwrap();
+    assert_eq!(episode.channel(), Channel::Custom("partner".to_string()));
+    assert_eq!(episode.external_id, None)
+}
+
+#[test]
+fn media_collection_dispatch() {
+    fn kind(value: serde_json::Value) -> &'static str {
+        match serde_json::from_value::<MediaCollection>(value).unwrap() {
+            MediaCollection::Series(_) => "series",
+            MediaCollection::Season(_) => "season",
+            MediaCollection::Episode(_) => "episode",
+            MediaCollection::MovieListing(_) => "movie_listing",
+            MediaCollection::Movie(_) => "movie",
+            MediaCollection::MusicVideo(_) => "music_video",
+            MediaCollection::Concert(_) => "concert",
+            _ => "unknown",
+        }
+    }
+
+    let matrix = [
+        // the type field has the highest priority
+        (
+            json!({ "type": "episode", "series_metadata": {}, "series_id": "GY8VEQ95Y" }),
+            "episode",
+        ),
+        (
+            json!({ "type": "series", "episode_metadata": {}, "sequence_number": 1.0 }),
+            "series",
+        ),
+        (
+            json!({ "type": "movie", "movie_listing_metadata": {} }),
+            "movie",
+        ),
+        // metadata blocks are checked in a fixed order if the type is missing or unknown
+        (
+            json!({ "episode_metadata": {}, "series_metadata": {} }),
+            "episode",
+        ),
+        (
+            json!({ "type": "unknown", "season_metadata": {}, "series_metadata": {} }),
+            "season",
+        ),
+        (
+            json!({ "series_metadata": {}, "movie_listing_metadata": {} }),
+            "series",
+        ),
+        (
+            json!({ "movie_metadata": {}, "movie_listing_metadata": {} }),
+            "movie",
+        ),
+        // metadata blocks win over kind specific fields
+        (
+            json!({ "series_metadata": {}, "sequence_number": 1.0 }),
+            "series",
+        ),
+        // kind specific fields of flat (non panel) payloads
+        (
+            json!({ "series_launch_year": 2018, "number_of_episodes": 24 }),
+            "series",
+        ),
+        (
+            json!({ "number_of_episodes": 24, "sequence_number": 1.0 }),
+            "season",
+        ),
+        (
+            json!({ "sequence_number": 1.0, "series_id": "GY8VEQ95Y" }),
+            "episode",
+        ),
+        (json!({ "movie_release_year": 2018 }), "movie_listing"),
+        (json!({ "movie_listing_title": "Movie" }), "movie"),
+        (json!({ "animeIds": [], "availability": {} }), "music_video"),
+        (json!({ "availability": {} }), "concert"),
+    ];
+    for (value, expected) in matrix {
+        assert_eq!(kind(value.clone()), expected, "{value}")
+    }
+
+    assert!(serde_json::from_value::<MediaCollection>(json!({ "id": "GY8VEQ95Y" })).is_err())
+}
+
+#[test]
+fn season_images() {
+    let image = json!({
+        "source": "https://imgsrv.crunchyroll.com/cdn-cgi/image/fit=contain,format=auto,quality=85,width=480,height=720/catalog/crunchyroll/f1b2a5b5a8b26c0f5ba8a1b9b8d0bdc1.jpe",
+        "type": "poster_tall",
+        "height": 720,
+        "width": 480
+    });
+    let season: Season = serde_json::from_value(json!({
+        "id": "GRZXCMN1W",
+        "images": { "poster_tall": [[image]] }
+    }))
+    .unwrap();
+    assert_eq!(season.images.poster_tall.len(), 1);
+    assert!(season.images.poster_wide.is_empty());
+    let media = MediaCollection::Season(season);
+    assert_eq!(media.as_media_base().images.len(), 1);
+
+    // seasons without images are sending an empty array or object instead
+    for images in [json!([]), json!({}), json!(null)] {
+        let season: Season =
+            serde_json::from_value(json!({ "id": "GRZXCMN1W", "images": images })).unwrap();
+        assert!(season.images.poster_tall.is_empty());
+        assert!(season.images.poster_wide.is_empty())
+    }
+}
+
+#[test]
+fn episode_optional_dates() {
+    let episode: Episode = serde_json::from_value(json!({
+        "id": "GRDQPM1ZY",
+        "free_available_date": "2023-01-08T15:00:00Z",
+        "availability_starts": "1970-01-01T00:00:00Z",
+        "availability_ends": null
+    }))
+    .unwrap();
+    assert_eq!(
+        episode
+            .free_available_date_opt()
+            .map(|date| date.to_rfc3339()),
+        Some("2023-01-08T15:00:00+00:00".to_string())
+    );
+    assert!(episode.premium_available_date_opt().is_none());
+    assert!(episode.episode_air_date_opt().is_none());
+    assert!(episode.availability_ends_opt().is_none());
+    // a real epoch date is distinguishable from a missing one
+    assert_eq!(
+        episode
+            .availability_starts_opt()
+            .map(|date| date.timestamp()),
+        Some(0)
+    )
+}
+
+#[test]
+fn media_collection_search_metadata() {
+    let results: Vec<MediaCollection> = serde_json::from_value(json!([
+        {
+            "id": "GY8VEQ95Y",
+            "type": "series",
+            "search_metadata": { "score": 12.5, "rank": 2, "popularity_score": 0.8 }
+        },
+        {
+            "id": "GRDQPM1ZY",
+            "type": "episode",
+            "search_metadata": { "score": 42.0, "rank": 1 }
+        },
+        { "id": "GRZXCMN1W", "type": "season" }
+    ]))
+    .unwrap();
+
+    let series_metadata = results[0].search_metadata().unwrap();
+    assert_eq!(series_metadata.score, 12.5);
+    assert_eq!(series_metadata.rank, 2);
+    assert_eq!(series_metadata.popularity_score, 0.8);
+    assert_eq!(results[1].search_metadata().unwrap().score, 42.0);
+    assert!(results[2].search_metadata().is_none());
+
+    let best = results
+        .iter()
+        .filter_map(|media| {
+            media
+                .search_metadata()
+                .map(|metadata| (media, metadata.score))
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .unwrap();
+    assert_eq!(best.0.as_media_base().id, "GRDQPM1ZY")
+}
+
+#[test]
+fn media_collection_kind() {
+    let matrix = [
+        (json!({ "type": "series" }), MediaKind::Series),
+        (json!({ "type": "season" }), MediaKind::Season),
+        (json!({ "type": "episode" }), MediaKind::Episode),
+        (json!({ "type": "movie_listing" }), MediaKind::MovieListing),
+        (json!({ "type": "movie" }), MediaKind::Movie),
+        (
+            json!({ "animeIds": [], "availability": {} }),
+            MediaKind::Music(MusicKind::Video),
+        ),
+        (
+            json!({ "availability": {} }),
+            MediaKind::Music(MusicKind::Concert),
+        ),
+    ];
+    for (value, expected) in matrix {
+        let media: MediaCollection = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(media.kind(), expected, "{value}")
+    }
+}
+
+#[test]
+fn panel_wrapped_episode() {
+    let wrapped = json!({
+        "fully_watched": false,
+        "playhead": 120,
+        "panel": {
+            "id": "GRDQPM1ZY",
+            "type": "episode",
+            "title": "To You, in 2000 Years: The Fall of Shiganshina, Part 1",
+            "episode_metadata": {
+                "series_id": "GR751KNZY",
+                "sequence_number": 1.0
+            }
+        }
+    });
+
+    let media: MediaCollection = serde_json::from_value(wrapped.clone()).unwrap();
+    let MediaCollection::Episode(episode) = media else {
+        panic!("panel wasn't deserialized as episode")
+    };
+    assert_eq!(episode.id, "GRDQPM1ZY");
+    assert_eq!(episode.series_id, "GR751KNZY");
+
+    let episode: Episode = serde_json::from_value(wrapped).unwrap();
+    assert_eq!(episode.id, "GRDQPM1ZY");
+    assert_eq!(episode.sequence_number, 1.0)
+}
+
+#[test]
+fn rating_distribution() {
+    let rating: Rating = serde_json::from_value(json!({
+        "1s": { "displayed": "12", "unit": "", "percentage": 1 },
+        "2s": { "displayed": "40", "unit": "", "percentage": 2 },
+        "3s": { "displayed": "150", "unit": "", "percentage": 7 },
+        "4s": { "displayed": "298", "unit": "", "percentage": 14 },
+        "5s": { "displayed": "1.6", "unit": "K", "percentage": 76 },
+        "total": 2100,
+        "average": "4.6",
+        "rating": ""
+    }))
+    .unwrap();
+
+    assert_eq!(rating.distribution(), [12, 40, 150, 298, 1596]);
+    assert_eq!(rating.total(), 2100)
+}
+
+#[test]
+fn music_panel_type() {
+    let music_video: MediaCollection =
+        serde_json::from_value(json!({ "id": "MV3B9C1E1B", "type": "musicVideo" })).unwrap();
+    assert!(matches!(music_video, MediaCollection::MusicVideo(_)));
+    assert_eq!(music_video.kind(), MediaKind::Music(MusicKind::Video));
+
+    let concert: MediaCollection =
+        serde_json::from_value(json!({ "id": "MC2E2AC135", "type": "musicConcert" })).unwrap();
+    assert!(matches!(concert, MediaCollection::Concert(_)));
+    assert_eq!(concert.kind(), MediaKind::Music(MusicKind::Concert))
+}
+
+#[test]
+fn subscription_trial_and_grace_period() {
+    let subscription: Subscription = serde_json::from_value(json!({
+        "id": "sub",
+        "tier": "fan",
+        "status": "active",
+        "is_free_trial": true
+    }))
+    .unwrap();
+    assert!(subscription.is_free_trial);
+    assert!(!subscription.is_in_grace_period());
+
+    let subscription: Subscription = serde_json::from_value(json!({
+        "id": "sub",
+        "tier": "mega_fan",
+        "status": "in_grace",
+        "active_free_trial": false
+    }))
+    .unwrap();
+    assert!(!subscription.is_free_trial);
+    assert!(subscription.is_in_grace_period());
+    assert_eq!(subscription.status, SubscriptionStatus::InGrace)
+}
@@ -0,0 +1,81 @@
+// the partial json used here would be rejected when testing with strict deserialization
+#![cfg(not(feature = "__test_strict"))]
+
+use chrono::Duration;
+use crunchyroll_rs::Episode;
+use serde_json::json;
+
+fn episode_duration(duration: serde_json::Value) -> Duration {
+    serde_json::from_value::<Episode>(json!({ "duration_ms": duration }))
+        .unwrap()
+        .duration
+}
+
+#[test]
+fn duration_integer_millis() {
+    assert_eq!(
+        episode_duration(json!(1425000)),
+        Duration::try_seconds(1425).unwrap()
+    )
+}
+
+#[test]
+fn duration_float_seconds() {
+    assert_eq!(
+        episode_duration(json!(1425.5)),
+        Duration::try_milliseconds(1425500).unwrap()
+    )
+}
+
+#[test]
+fn duration_iso8601() {
+    assert_eq!(
+        episode_duration(json!("PT23M45S")),
+        Duration::try_seconds(1425).unwrap()
+    );
+    assert_eq!(
+        episode_duration(json!("PT1H0M0.5S")),
+        Duration::try_milliseconds(3600500).unwrap()
+    )
+}
+
+#[test]
+fn duration_invalid() {
+    assert!(serde_json::from_value::<Episode>(json!({ "duration_ms": "23 minutes" })).is_err())
+}
+
+#[test]
+fn duration_string_integer_millis() {
+    assert_eq!(
+        episode_duration(json!("1425000")),
+        Duration::try_seconds(1425).unwrap()
+    )
+}
+
+#[test]
+fn duration_iso8601_days() {
+    assert_eq!(
+        episode_duration(json!("P1DT1S")),
+        Duration::try_seconds(86401).unwrap()
+    )
+}
+
+#[test]
+fn duration_invalid_iso8601() {
+    for invalid in ["P", "PT", "PT-5S", "PT5", "P1H"] {
+        assert!(
+            serde_json::from_value::<Episode>(json!({ "duration_ms": invalid })).is_err(),
+            "{invalid}"
+        )
+    }
+}
+
+#[test]
+fn duration_negative() {
+    for invalid in [json!(-1000), json!(-1.5), json!("-1000")] {
+        assert!(
+            serde_json::from_value::<Episode>(json!({ "duration_ms": invalid })).is_err(),
+            "{invalid}"
+        )
+    }
+}
@@ -1,6 +1,12 @@
+use crate::utils::store::get_store;
 use crate::utils::Store;
 use crate::utils::SESSION;
-use crunchyroll_rs::Episode;
+#[cfg(feature = "hls-stream")]
+use crunchyroll_rs::error::Error;
+use crunchyroll_rs::media::StreamOptions;
+#[cfg(feature = "hls-stream")]
+use crunchyroll_rs::media::{CancellationToken, StreamPreferences};
+use crunchyroll_rs::{Crunchyroll, Episode, Locale};
 
 mod utils;
 
@@ -31,6 +37,24 @@ async fn episode_stream() {
     assert_result!(episode.stream().await)
 }
 
+#[tokio::test]
+async fn episode_stream_with() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    let stream = episode
+        .stream_with(
+            StreamOptions::default()
+                .hardsub(Locale::en_US)
+                .include_drm(false),
+        )
+        .await;
+    assert_result!(stream);
+    for (locale, variants) in stream.unwrap().variants {
+        assert!([Locale::en_US, Locale::Custom("".into())].contains(&locale));
+        assert!(variants.drm_adaptive_hls.is_none() && variants.drm_adaptive_dash.is_none())
+    }
+}
+
 #[tokio::test]
 async fn episode_alternative_stream() {
     let episode = START_EPISODE.get().await.unwrap();
@@ -52,6 +76,13 @@ async fn episode_set_playhead() {
     assert_result!(episode.set_playhead(69).await)
 }
 
+#[tokio::test]
+async fn episode_reset_playhead() {
+    let episode = END_EPISODE.get().await.unwrap();
+
+    assert_result!(episode.reset_playhead().await)
+}
+
 #[tokio::test]
 async fn episode_some_previous() {
     let episode = END_EPISODE.get().await.unwrap();
@@ -80,6 +111,30 @@ async fn episode_none_next() {
     assert_result!(episode.next().await)
 }
 
+#[tokio::test]
+async fn episode_next_preferred_audio() {
+    // same as `utils::SESSION` but with a preferred audio locale
+    let raw_session = get_store("session".into()).unwrap();
+    let builder = Crunchyroll::builder().preferred_audio_locale(Locale::en_US);
+    let crunchy = match raw_session.split_once(':').unwrap_or(("", "")) {
+        ("refresh_token", token) => builder.login_with_refresh_token(token).await.unwrap(),
+        ("etp_rt", token) => builder.login_with_etp_rt(token).await.unwrap(),
+        _ => panic!("invalid session '{raw_session}'"),
+    };
+    let episode: Episode = crunchy.media_from_id("GRDKJZ81Y").await.unwrap();
+
+    let mut next = episode.next().await.unwrap().unwrap().media;
+    // the preferred audio can only be returned if the next episode has a version with it
+    if next
+        .available_versions()
+        .await
+        .unwrap()
+        .contains(&Locale::en_US)
+    {
+        assert_eq!(next.audio_locale, Locale::en_US)
+    }
+}
+
 #[tokio::test]
 async fn episode_versions() {
     let mut episode = END_EPISODE.get().await.unwrap().clone();
@@ -92,3 +147,198 @@ async fn episode_skip_events() {
     let episode = START_EPISODE.get().await.unwrap();
     episode.skip_events().await.unwrap();
 }
+
+#[cfg(feature = "parse")]
+#[tokio::test]
+async fn episode_url() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    assert!(matches!(
+        crunchyroll_rs::parse_url(episode.url()),
+        Some(crunchyroll_rs::UrlType::EpisodeOrMovie(id)) if id == episode.id
+    ))
+}
+
+#[tokio::test]
+async fn episode_eligible_regions() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    for region in episode.eligible_regions() {
+        assert!(!region.is_empty());
+        assert!(!region.contains(|c: char| c == ',' || c.is_whitespace()))
+    }
+}
+
+#[tokio::test]
+async fn episode_refresh() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    let refreshed = episode.refresh().await;
+    assert_result!(refreshed);
+    assert_eq!(refreshed.unwrap().id, episode.id)
+}
+
+#[tokio::test]
+async fn episode_fresh_stream() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    let stream = episode.fresh_stream().await.unwrap();
+    let cached = episode.fresh_stream().await.unwrap();
+    assert_eq!(stream.media_id, cached.media_id)
+}
+
+#[tokio::test]
+async fn episode_playheads_for() {
+    let crunchy = SESSION.get().await.unwrap();
+    let ids = vec![
+        START_EPISODE.get().await.unwrap().id.clone(),
+        END_EPISODE.get().await.unwrap().id.clone(),
+    ];
+
+    let playheads = crunchy.playheads_for(&ids).await.unwrap();
+    assert!(ids.iter().all(|id| playheads.contains_key(id)))
+}
+
+#[tokio::test]
+async fn episode_subtitle_locales() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    assert_eq!(
+        episode.subtitle_locale_count(),
+        episode.subtitle_locales.len()
+    );
+    for locale in &episode.subtitle_locales {
+        assert!(episode.has_subtitles_in(locale))
+    }
+}
+
+#[cfg(feature = "hls-stream")]
+#[tokio::test]
+async fn episode_quality_options() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    let quality_options = episode.quality_options(&episode.audio_locale).await;
+    assert_result!(quality_options);
+    assert!(quality_options
+        .unwrap()
+        .windows(2)
+        .all(|w| w[0].resolution.width >= w[1].resolution.width))
+}
+
+#[cfg(feature = "hls-stream")]
+#[tokio::test]
+async fn episode_stream_summary() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    let summary = episode.stream_summary().await;
+    assert_result!(summary);
+    let summary = summary.unwrap();
+    assert!(summary.audio_locales.contains(&episode.audio_locale));
+    assert!(summary.drm_only || !summary.qualities.is_empty())
+}
+
+/// Unique temporary directory, so that tests running in parallel (or crashed previous runs) don't
+/// interfere with each other.
+#[cfg(feature = "hls-stream")]
+fn archive_dir() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("crunchyroll-rs-archive-{}", rand::random::<u64>()))
+}
+
+#[cfg(feature = "hls-stream")]
+#[tokio::test]
+async fn episode_archive_to() {
+    let crunchy = SESSION.get().await.unwrap();
+    if !crunchy.can_download_offline().await {
+        // the video download requires the offline viewing benefit
+        return;
+    }
+
+    let episode = START_EPISODE.get().await.unwrap();
+    let dir = archive_dir();
+
+    let archive = episode
+        .archive_to(&dir, StreamPreferences::default(), None)
+        .await;
+    assert_result!(archive);
+    let archive = archive.unwrap();
+    let video = archive.video;
+    assert_result!(video);
+    for (_, subtitle) in archive.subtitles {
+        assert_result!(subtitle)
+    }
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[cfg(feature = "hls-stream")]
+#[tokio::test]
+async fn episode_archive_to_cancelled() {
+    let episode = START_EPISODE.get().await.unwrap();
+    let dir = archive_dir();
+
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+    let archive = episode
+        .archive_to(&dir, StreamPreferences::default(), Some(cancellation))
+        .await;
+    assert_result!(archive);
+    let archive = archive.unwrap();
+    // the token is checked before the offline viewing benefit of the account, so this doesn't
+    // depend on the account subscription
+    assert!(matches!(archive.video, Err(Error::Cancelled { .. })));
+    for (_, subtitle) in archive.subtitles {
+        assert!(matches!(subtitle, Err(Error::Cancelled { .. })))
+    }
+    if let Some(thumbnail) = archive.thumbnail {
+        assert!(matches!(thumbnail, Err(Error::Cancelled { .. })))
+    }
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[cfg(feature = "parse")]
+#[tokio::test]
+async fn episode_from_invalid_external_id() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    assert!(crunchy.media_from_external_id("SRZ.259963").await.is_err());
+    assert!(crunchy.media_from_external_id("").await.is_err())
+}
+
+#[tokio::test]
+async fn episode_is_last_in_season() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    let is_last = episode.is_last_in_season().await;
+    assert_result!(is_last);
+    assert!(!is_last.unwrap())
+}
+
+#[tokio::test]
+async fn episode_resolved_audio_locale() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    let audio_locale = episode.resolved_audio_locale().await;
+    assert_result!(audio_locale);
+    assert_ne!(audio_locale.unwrap(), Locale::default())
+}
+
+#[tokio::test]
+async fn episode_all_subtitles() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    let all_subtitles = episode.all_subtitles().await;
+    assert_result!(all_subtitles);
+    let all_subtitles = all_subtitles.unwrap();
+    for locale in episode.stream().await.unwrap().subtitles.keys() {
+        assert!(all_subtitles.contains_key(locale))
+    }
+}
+
+#[tokio::test]
+async fn episode_parts() {
+    let episode = START_EPISODE.get().await.unwrap();
+    let parts = episode.parts();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].id, episode.id)
+}
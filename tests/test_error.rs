@@ -0,0 +1,47 @@
+use crunchyroll_rs::error::Error;
+use reqwest::StatusCode;
+
+fn request_error(status: Option<StatusCode>) -> Error {
+    Error::Request {
+        message: "".to_string(),
+        status,
+        url: "n/a".to_string(),
+        connection_failed: false,
+    }
+}
+
+#[test]
+fn error_is_retryable() {
+    assert!(request_error(Some(StatusCode::INTERNAL_SERVER_ERROR)).is_retryable());
+    assert!(request_error(Some(StatusCode::SERVICE_UNAVAILABLE)).is_retryable());
+    assert!(request_error(Some(StatusCode::TOO_MANY_REQUESTS)).is_retryable());
+    assert!(Error::Request {
+        message: "".to_string(),
+        status: None,
+        url: "n/a".to_string(),
+        connection_failed: true,
+    }
+    .is_retryable());
+
+    assert!(!request_error(None).is_retryable());
+    assert!(!request_error(Some(StatusCode::NOT_FOUND)).is_retryable());
+    assert!(!request_error(Some(StatusCode::UNAUTHORIZED)).is_retryable());
+    assert!(!Error::Input {
+        message: "".to_string()
+    }
+    .is_retryable());
+}
+
+#[test]
+fn error_is_auth_error() {
+    assert!(request_error(Some(StatusCode::UNAUTHORIZED)).is_auth_error());
+    assert!(request_error(Some(StatusCode::FORBIDDEN)).is_auth_error());
+    assert!(Error::Authentication {
+        message: "".to_string()
+    }
+    .is_auth_error());
+
+    assert!(!request_error(Some(StatusCode::NOT_FOUND)).is_auth_error());
+    assert!(!request_error(Some(StatusCode::TOO_MANY_REQUESTS)).is_auth_error());
+    assert!(!request_error(None).is_auth_error());
+}
@@ -42,3 +42,8 @@ async fn recommendations() {
         .await
         .unwrap())
 }
+
+#[tokio::test]
+async fn home_rails() {
+    assert_result!(SESSION.get().await.unwrap().home_rails().await)
+}
@@ -0,0 +1,93 @@
+// the fixtures only contain the most common fields which is rejected by strict deserialization
+#![cfg(all(feature = "testing", not(feature = "__test_strict")))]
+
+use crunchyroll_rs::error::Error;
+use crunchyroll_rs::media::MediaKind;
+use crunchyroll_rs::testing::{media_collection_fixture, MediaFixture};
+use crunchyroll_rs::{testing, Episode, Locale, Movie, MovieListing, Season, Series};
+use serde_json::json;
+
+#[test]
+fn media_fixtures() {
+    let series = Series::test_fixture(json!(null)).unwrap();
+    assert_eq!(series.id, "GY8VEQ95Y");
+    assert_eq!(series.season_count, 1);
+
+    let season = Season::test_fixture(json!(null)).unwrap();
+    assert_eq!(season.series_id, series.id);
+
+    let episode = Episode::test_fixture(json!(null)).unwrap();
+    assert_eq!(episode.season_id, season.id);
+    assert_eq!(episode.audio_locale, Locale::ja_JP);
+    assert!(episode.free_available_date_opt().is_some());
+
+    let movie_listing = MovieListing::test_fixture(json!(null)).unwrap();
+    let movie = Movie::test_fixture(json!(null)).unwrap();
+    assert_eq!(movie.movie_listing_id, movie_listing.id)
+}
+
+#[test]
+fn media_fixture_overrides() {
+    let episode = Episode::test_fixture(json!({
+        "title": "Custom title",
+        "sequence_number": 12.0
+    }))
+    .unwrap();
+    assert_eq!(episode.title, "Custom title");
+    assert_eq!(episode.sequence_number, 12.0);
+    assert_eq!(episode.series_id, "GY8VEQ95Y");
+
+    assert!(Episode::test_fixture(json!([])).is_err())
+}
+
+#[test]
+fn media_collection_fixtures() {
+    let matrix = [
+        (testing::SERIES, MediaKind::Series),
+        (testing::SEASON, MediaKind::Season),
+        (testing::EPISODE, MediaKind::Episode),
+        (testing::MOVIE_LISTING, MediaKind::MovieListing),
+        (testing::MOVIE, MediaKind::Movie),
+    ];
+    for (fixture, kind) in matrix {
+        assert_eq!(media_collection_fixture(fixture).unwrap().kind(), kind)
+    }
+}
+
+#[tokio::test]
+async fn maturity_rating_definitions() {
+    let series = Series::test_fixture(json!({ "maturity_ratings": ["TV-14"] })).unwrap();
+
+    let crunchy = testing::crunchyroll_fixture(Locale::en_US);
+    let definitions = crunchy.maturity_rating_definitions().await.unwrap();
+    for rating in &series.maturity_ratings {
+        assert!(definitions.contains_key(rating), "{rating}")
+    }
+    assert_eq!(definitions["TV-14"].name, "Parents Strongly Cautioned");
+
+    let crunchy = testing::crunchyroll_fixture(Locale::de_DE);
+    let definitions = crunchy.maturity_rating_definitions().await.unwrap();
+    assert_eq!(definitions["TV-14"].name, "Eltern ausdrücklich gewarnt");
+
+    // locales without translation fall back to english
+    let crunchy = testing::crunchyroll_fixture(Locale::ja_JP);
+    let definitions = crunchy.maturity_rating_definitions().await.unwrap();
+    assert_eq!(definitions["TV-14"].name, "Parents Strongly Cautioned")
+}
+
+#[tokio::test]
+async fn media_from_invalid_id() {
+    // the fixture isn't logged in, so every request which reaches the api would fail with another
+    // error than `Error::Input`
+    let crunchy = testing::crunchyroll_fixture(Locale::en_US);
+    for id in ["", "GRDV0019R/"] {
+        assert!(matches!(
+            crunchy.media_from_id::<Series>(id).await,
+            Err(Error::Input { .. })
+        ));
+        assert!(matches!(
+            crunchy.media_collection_from_id(id).await,
+            Err(Error::Input { .. })
+        ))
+    }
+}
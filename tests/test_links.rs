@@ -0,0 +1,20 @@
+use crunchyroll_rs::media::MediaLinks;
+use serde_json::json;
+
+#[test]
+fn links_relative_and_absolute() {
+    let relative: MediaLinks = serde_json::from_value(json!({
+        "episode/streams": {"href": "/content/v2/cms/videos/GRDKJZ81Y/streams"}
+    }))
+    .unwrap();
+    let absolute: MediaLinks = serde_json::from_value(json!({
+        "episode/streams": {"href": "https://www.crunchyroll.com/content/v2/cms/videos/GRDKJZ81Y/streams"}
+    }))
+    .unwrap();
+
+    assert_eq!(
+        relative.streams.as_deref(),
+        Some("https://www.crunchyroll.com/content/v2/cms/videos/GRDKJZ81Y/streams")
+    );
+    assert_eq!(relative.streams, absolute.streams)
+}
@@ -0,0 +1,52 @@
+use crunchyroll_rs::Locale;
+
+#[test]
+fn locale_language_and_region_code() {
+    let table = [
+        (Locale::ja_JP, "ja", Some("JP")),
+        (Locale::en_US, "en", Some("US")),
+        (Locale::es_419, "es", Some("419")),
+        (Locale::es_LA, "es", Some("419")),
+        (Locale::zh_HK, "zh", Some("HK")),
+        (Locale::ar_ME, "ar", None),
+        (Locale::Custom("nl-NL".into()), "nl", Some("NL")),
+        (Locale::Custom("sr_Latn_RS".into()), "sr", Some("RS")),
+        (Locale::Custom("sv".into()), "sv", None),
+        (Locale::Custom("".into()), "", None),
+    ];
+    for (locale, language, region) in table {
+        assert_eq!(locale.language_code(), language, "{locale}");
+        assert_eq!(locale.region_code(), region, "{locale}")
+    }
+}
+
+#[test]
+fn locale_from_bcp47() {
+    let table = [
+        ("ja-JP", Locale::ja_JP),
+        ("ja_jp", Locale::ja_JP),
+        ("JA-jp", Locale::ja_JP),
+        ("ja", Locale::ja_JP),
+        ("es-419", Locale::es_419),
+        ("es-LA", Locale::es_LA),
+        ("zh-HK", Locale::zh_HK),
+        ("zh-Hant-TW", Locale::zh_TW),
+        ("ar", Locale::ar_ME),
+        ("ar-SA", Locale::ar_SA),
+        ("en", Locale::Custom("en".into())),
+        ("nl-NL", Locale::Custom("nl-NL".into())),
+    ];
+    for (tag, locale) in table {
+        assert_eq!(Locale::from_bcp47(tag), locale, "{tag}")
+    }
+
+    for locale in Locale::all() {
+        let tag = match locale.region_code() {
+            Some(region) => format!("{}-{region}", locale.language_code()),
+            None => locale.language_code().to_string(),
+        };
+        let converted = Locale::from_bcp47(&tag);
+        assert_eq!(converted.language_code(), locale.language_code(), "{tag}");
+        assert_eq!(converted.region_code(), locale.region_code(), "{tag}")
+    }
+}
@@ -0,0 +1,27 @@
+use crunchyroll_rs::media::MediaId;
+use std::str::FromStr;
+
+#[test]
+fn media_id_from_str() {
+    assert_eq!(
+        MediaId::from_str("GRDV0019R").unwrap().as_str(),
+        "GRDV0019R"
+    );
+    assert_eq!(
+        MediaId::from_str("MV63FA4ED1").unwrap().as_str(),
+        "MV63FA4ED1"
+    );
+
+    assert!(MediaId::from_str("").is_err());
+    assert!(MediaId::from_str("GRDV0019R/").is_err());
+    assert!(MediaId::from_str("https://www.crunchyroll.com/series/GRDV0019R").is_err());
+}
+
+#[test]
+fn media_id_conversions() {
+    let id = String::from("GRDV0019R");
+
+    assert_eq!(MediaId::from(id.as_str()), MediaId::from(&id));
+    assert_eq!(String::from(MediaId::from(id.clone())), id);
+    assert_eq!(MediaId::from(&id).to_string(), id);
+}
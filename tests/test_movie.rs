@@ -26,3 +26,11 @@ async fn movie_stream() {
 async fn movie_alternative_stream() {
     assert_result!(MOVIE.get().await.unwrap().alternative_stream().await)
 }
+
+#[tokio::test]
+async fn movie_parts() {
+    let movie = MOVIE.get().await.unwrap();
+    let parts = movie.parts();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].id, movie.id)
+}
@@ -21,3 +21,19 @@ async fn movie_listing_from_id() {
 async fn movies() {
     assert_result!(MOVIE_LISTING.get().await.unwrap().movies().await)
 }
+
+#[tokio::test]
+async fn first_movie() {
+    assert_result!(MOVIE_LISTING.get().await.unwrap().first_movie().await)
+}
+
+#[tokio::test]
+async fn available_movies() {
+    let movie_listing = MOVIE_LISTING.get().await.unwrap();
+
+    let available_movies = movie_listing.available_movies().await;
+    assert_result!(available_movies);
+    for movie in available_movies.unwrap() {
+        assert!(movie.available().await)
+    }
+}
@@ -82,3 +82,33 @@ fn parse_concert_url() {
         unreachable!()
     }
 }
+
+fn assert_round_trip(url_type: UrlType) {
+    let url = crunchyroll_rs::build_url(&url_type);
+    assert_eq!(crunchyroll_rs::parse_url(&url), Some(url_type), "{url}")
+}
+
+#[test]
+fn round_trip_series_url() {
+    assert_round_trip(UrlType::Series("GY8VEQ95Y".to_string()))
+}
+
+#[test]
+fn round_trip_movie_listing_url() {
+    assert_round_trip(UrlType::MovieListing("G6YPJ8N3Y".to_string()))
+}
+
+#[test]
+fn round_trip_episode_or_movie_url() {
+    assert_round_trip(UrlType::EpisodeOrMovie("GRDQPM1ZY".to_string()))
+}
+
+#[test]
+fn round_trip_music_video_url() {
+    assert_round_trip(UrlType::MusicVideo("MV2FD1FECE".to_string()))
+}
+
+#[test]
+fn round_trip_concert_url() {
+    assert_round_trip(UrlType::Concert("MC2E2AC135".to_string()))
+}
@@ -0,0 +1,74 @@
+use chrono::Duration;
+use crunchyroll_rs::media::PlayheadInformation;
+
+fn playhead_json(playhead: u32) -> serde_json::Value {
+    serde_json::json!({
+        "playhead": playhead,
+        "content_id": "GRDQPM1ZY",
+        "fully_watched": false,
+        "last_modified": "2024-01-01T00:00:00Z"
+    })
+}
+
+#[test]
+fn playhead_to_duration() {
+    let playhead: PlayheadInformation = serde_json::from_value(playhead_json(754)).unwrap();
+
+    assert_eq!(
+        playhead.playhead_duration(),
+        Duration::try_seconds(754).unwrap()
+    )
+}
+
+#[test]
+fn duration_to_playhead() {
+    assert_eq!(
+        PlayheadInformation::duration_to_playhead(Duration::try_seconds(754).unwrap()),
+        754
+    )
+}
+
+#[test]
+fn duration_to_playhead_floors_sub_seconds() {
+    assert_eq!(
+        PlayheadInformation::duration_to_playhead(Duration::try_milliseconds(999).unwrap()),
+        0
+    );
+    assert_eq!(
+        PlayheadInformation::duration_to_playhead(Duration::try_milliseconds(754_999).unwrap()),
+        754
+    )
+}
+
+#[test]
+fn duration_to_playhead_negative() {
+    assert_eq!(
+        PlayheadInformation::duration_to_playhead(Duration::try_seconds(-10).unwrap()),
+        0
+    )
+}
+
+#[test]
+fn duration_to_playhead_long_movie() {
+    // 10 hours
+    let duration = Duration::try_hours(10).unwrap();
+    let playhead = PlayheadInformation::duration_to_playhead(duration);
+
+    assert_eq!(playhead, 36_000);
+    assert_eq!(
+        serde_json::from_value::<PlayheadInformation>(playhead_json(playhead))
+            .unwrap()
+            .playhead_duration(),
+        duration
+    )
+}
+
+#[test]
+fn duration_to_playhead_overflow() {
+    assert_eq!(
+        PlayheadInformation::duration_to_playhead(
+            Duration::try_seconds(u32::MAX as i64 + 1).unwrap()
+        ),
+        u32::MAX
+    )
+}
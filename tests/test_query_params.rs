@@ -0,0 +1,191 @@
+// the requests are recorded with a tower middleware
+#![cfg(feature = "tower")]
+
+use crunchyroll_rs::common::{Pagination, Request as PaginationItem};
+use crunchyroll_rs::error::Error;
+use crunchyroll_rs::search::BrowseOptions;
+use crunchyroll_rs::{Crunchyroll, Episode, Series};
+use futures_util::StreamExt;
+use reqwest::{Client, Request, Response, Url};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tower_service::Service;
+use utils::store::get_store;
+
+mod utils;
+
+/// Middleware which records the url of every request before sending it.
+#[derive(Clone, Default)]
+struct RecordUrls {
+    client: Client,
+    urls: Arc<Mutex<Vec<Url>>>,
+}
+
+impl RecordUrls {
+    /// Return the query parameters of every recorded request whose path ends with `path`, in the
+    /// order the requests were made.
+    fn queries(&self, path: &str) -> Vec<HashMap<String, String>> {
+        self.urls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|url| url.path().ends_with(path))
+            .map(|url| {
+                url.query_pairs()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl Service<Request> for RecordUrls {
+    type Response = Response;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        self.urls.lock().unwrap().push(req.url().clone());
+        let client = self.client.clone();
+        Box::pin(async move { Ok(client.execute(req).await?) })
+    }
+}
+
+async fn login(recorder: &RecordUrls) -> Crunchyroll {
+    login_with_extra_query(recorder, vec![]).await
+}
+
+async fn login_with_extra_query(
+    recorder: &RecordUrls,
+    extra_query: Vec<(String, String)>,
+) -> Crunchyroll {
+    // same as `utils::SESSION` but with the recording middleware
+    let raw_session = get_store("session".into()).unwrap();
+    let builder = Crunchyroll::builder()
+        .middleware(recorder.clone())
+        .extra_query(extra_query);
+    match raw_session.split_once(':').unwrap_or(("", "")) {
+        ("refresh_token", token) => builder.login_with_refresh_token(token).await.unwrap(),
+        ("etp_rt", token) => builder.login_with_etp_rt(token).await.unwrap(),
+        _ => panic!("invalid session '{raw_session}'"),
+    }
+}
+
+const PAGE_SIZE: usize = 5;
+
+/// Take one more item than fits on a page from `pagination` and check that the server respected
+/// the page size and offset: the first page must contain exactly [`PAGE_SIZE`] items (otherwise no
+/// second request would be necessary) and the second page must start at offset [`PAGE_SIZE`].
+async fn assert_offset_pagination<T: Debug + Default + DeserializeOwned + PaginationItem>(
+    recorder: &RecordUrls,
+    path: &str,
+    mut pagination: Pagination<T>,
+) -> Vec<T> {
+    pagination.page_size(PAGE_SIZE as u32);
+    let mut items = vec![];
+    for _ in 0..=PAGE_SIZE {
+        let item = pagination.next().await.unwrap();
+        assert_result!(item);
+        items.push(item.unwrap())
+    }
+
+    let queries = recorder.queries(path);
+    assert_eq!(queries.len(), 2, "{queries:?}");
+    for (query, start) in queries.iter().zip([0, PAGE_SIZE]) {
+        assert_eq!(query.get("n"), Some(&PAGE_SIZE.to_string()), "{query:?}");
+        assert_eq!(query.get("start"), Some(&start.to_string()), "{query:?}");
+        assert!(!query.contains_key("limit"), "{query:?}")
+    }
+    items
+}
+
+#[tokio::test]
+async fn browse_query_params() {
+    let recorder = RecordUrls::default();
+    let crunchy = login(&recorder).await;
+
+    let browse = crunchy.browse(BrowseOptions::default());
+    assert_offset_pagination(&recorder, "/discover/browse", browse).await;
+}
+
+#[tokio::test]
+async fn search_query_params() {
+    let recorder = RecordUrls::default();
+    let crunchy = login(&recorder).await;
+
+    let query = crunchy.query("a");
+    let series = assert_offset_pagination(&recorder, "/discover/search", query.series).await;
+    // the second page must not repeat the items of the first one
+    let last = series.last().unwrap();
+    assert!(
+        series[..PAGE_SIZE].iter().all(|s| s.id != last.id),
+        "{}",
+        last.id
+    )
+}
+
+#[tokio::test]
+async fn similar_query_params() {
+    let recorder = RecordUrls::default();
+    let crunchy = login(&recorder).await;
+
+    let series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    assert_offset_pagination(
+        &recorder,
+        "/discover/similar_to/GY8VEQ95Y",
+        series.similar(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn reviews_query_params() {
+    let recorder = RecordUrls::default();
+    let crunchy = login(&recorder).await;
+
+    let series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    let mut reviews = series.reviews(Default::default()).unwrap();
+    reviews.page_size(PAGE_SIZE as u32);
+    let result = reviews.next().await.unwrap();
+    assert_result!(result);
+    let queries = recorder.queries("/list");
+    let query = queries.last().unwrap();
+    assert_eq!(query.get("page"), Some(&"1".to_string()), "{query:?}");
+    assert_eq!(
+        query.get("page_size"),
+        Some(&PAGE_SIZE.to_string()),
+        "{query:?}"
+    )
+}
+
+#[tokio::test]
+async fn extra_query_params() {
+    let recorder = RecordUrls::default();
+    let crunchy =
+        login_with_extra_query(&recorder, vec![("ratings".to_string(), "true".to_string())]).await;
+
+    let episode: Episode = crunchy.media_from_id("GRDKJZ81Y").await.unwrap();
+    assert_result!(episode.skip_events().await);
+
+    // api requests get the extra parameter
+    let queries = recorder.queries("/cms/episodes/GRDKJZ81Y");
+    assert!(!queries.is_empty());
+    for query in &queries {
+        assert_eq!(query.get("ratings"), Some(&"true".to_string()), "{query:?}")
+    }
+    // requests to static assets don't
+    let queries = recorder.queries("/skip-events/production/GRDKJZ81Y.json");
+    assert!(!queries.is_empty());
+    for query in &queries {
+        assert!(!query.contains_key("ratings"), "{query:?}")
+    }
+}
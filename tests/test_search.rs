@@ -1,4 +1,5 @@
 use crate::utils::SESSION;
+use crunchyroll_rs::error::Error;
 use crunchyroll_rs::Locale;
 use futures_util::StreamExt;
 
@@ -11,6 +12,36 @@ async fn by_browse() {
     assert_result!(crunchy.browse(Default::default()).next().await.unwrap());
 }
 
+#[tokio::test]
+async fn browse_resume_pagination() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut browse = crunchy.browse(Default::default());
+    for _ in 0..3 {
+        browse.next().await.unwrap().unwrap();
+    }
+    let cursor = serde_json::to_string(&browse.cursor()).unwrap();
+    let expected = browse.next().await.unwrap().unwrap();
+
+    let mut resumed = crunchy.resume_pagination(
+        crunchy.browse(Default::default()),
+        serde_json::from_str(&cursor).unwrap(),
+    );
+    let next = resumed.next().await.unwrap();
+    assert_result!(next);
+    assert_eq!(
+        next.unwrap().as_media_base().id,
+        expected.as_media_base().id
+    )
+}
+
+#[tokio::test]
+async fn by_popular() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    assert_result!(crunchy.popular(Default::default()).next().await.unwrap());
+}
+
 #[tokio::test]
 async fn by_query() {
     let crunchy = SESSION.get().await.unwrap();
@@ -32,3 +63,43 @@ async fn simulcast_seasons() {
 
     assert_result!(crunchy.simulcast_seasons(Locale::en_US).await)
 }
+
+#[tokio::test]
+async fn by_browse_all_types() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut browse = crunchy.browse_all_types(Default::default());
+    browse.page_size(50);
+    let results: Vec<_> = browse.take(50).collect().await;
+    for result in results {
+        assert_result!(result)
+    }
+}
+
+#[tokio::test]
+async fn browse_facets() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    assert_result!(crunchy.browse_facets().await)
+}
+
+#[tokio::test]
+async fn by_query_special_characters() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    for query in ["attack on titan", "Re:Zero", "Kaguya & Miyuki", "🍙"] {
+        assert_result!(crunchy.query(query).top_results.next().await.unwrap())
+    }
+}
+
+#[tokio::test]
+async fn by_query_empty() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    for query in ["", "   "] {
+        assert!(matches!(
+            crunchy.query(query).top_results.next().await.unwrap(),
+            Err(Error::Input { .. })
+        ))
+    }
+}
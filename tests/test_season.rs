@@ -20,3 +20,56 @@ async fn season_from_id() {
 async fn season_episodes() {
     assert_result!(SEASON.get().await.unwrap().episodes().await)
 }
+
+#[tokio::test]
+async fn season_stream_urls() {
+    use crunchyroll_rs::common::StreamExt;
+    use crunchyroll_rs::media::StreamPreferences;
+
+    let stream_urls = SEASON
+        .get()
+        .await
+        .unwrap()
+        .stream_urls(StreamPreferences::default())
+        .await
+        .unwrap();
+    for (_, url) in stream_urls.collect::<Vec<_>>().await {
+        assert_result!(url)
+    }
+}
+
+#[tokio::test]
+async fn season_title_for() {
+    let season = SEASON.get().await.unwrap();
+
+    assert_result!(season.title_for(&crunchyroll_rs::Locale::de_DE).await)
+}
+
+#[tokio::test]
+async fn season_adjacent_seasons() {
+    let season = SEASON.get().await.unwrap();
+
+    let next_season = season.next_season().await;
+    assert_result!(next_season);
+    if let Some(next_season) = next_season.unwrap() {
+        assert!(next_season.season_sequence_number > season.season_sequence_number)
+    }
+
+    let previous_season = season.previous_season().await;
+    assert_result!(previous_season);
+    if let Some(previous_season) = previous_season.unwrap() {
+        assert!(previous_season.season_sequence_number < season.season_sequence_number)
+    }
+}
+
+#[tokio::test]
+async fn season_is_last_in_series() {
+    let season = SEASON.get().await.unwrap();
+
+    let is_last = season.is_last_in_series().await;
+    assert_result!(is_last);
+    assert_eq!(
+        is_last.unwrap(),
+        season.next_season().await.unwrap().is_none()
+    )
+}
@@ -23,6 +23,15 @@ async fn series_seasons() {
     assert_result!(SERIES.get().await.unwrap().seasons().await)
 }
 
+#[tokio::test]
+async fn series_is_currently_airing() {
+    let is_currently_airing = SERIES.get().await.unwrap().is_currently_airing().await;
+
+    assert_result!(is_currently_airing);
+    // the series has finished airing in 2018
+    assert!(!is_currently_airing.unwrap())
+}
+
 #[tokio::test]
 async fn series_featured_music() {
     assert_result!(SERIES.get().await.unwrap().featured_music().await)
@@ -32,3 +41,80 @@ async fn series_featured_music() {
 async fn series_similar() {
     assert_result!(SERIES.get().await.unwrap().similar().next().await.unwrap())
 }
+
+#[tokio::test]
+async fn series_verify_counts() {
+    assert_result!(SERIES.get().await.unwrap().verify_counts().await)
+}
+
+#[cfg(feature = "parse")]
+#[tokio::test]
+async fn series_url() {
+    let series = SERIES.get().await.unwrap();
+
+    assert!(matches!(
+        crunchyroll_rs::parse_url(series.url()),
+        Some(crunchyroll_rs::UrlType::Series(id)) if id == series.id
+    ))
+}
+
+#[tokio::test]
+async fn series_similar_with_meta() {
+    let series = SERIES.get().await.unwrap();
+
+    assert_result!(series.similar_with_meta(0, 10).await)
+}
+
+#[tokio::test]
+async fn series_episodes_stream() {
+    let mut episodes = SERIES.get().await.unwrap().episodes_stream();
+
+    while let Some(episode) = episodes.next().await {
+        assert_result!(episode)
+    }
+}
+
+#[tokio::test]
+async fn series_channel() {
+    let series = SERIES.get().await.unwrap();
+
+    assert_eq!(
+        series.channel(),
+        crunchyroll_rs::media::Channel::Crunchyroll
+    )
+}
+
+#[tokio::test]
+async fn series_content_availability_calendar() {
+    let calendar = SERIES
+        .get()
+        .await
+        .unwrap()
+        .content_availability_calendar()
+        .await;
+
+    assert_result!(calendar);
+    let calendar = calendar.unwrap();
+    assert!(calendar.iter().all(|(_, date)| date > &chrono::Utc::now()));
+    assert!(calendar.windows(2).all(|w| w[0].1 <= w[1].1))
+}
+
+#[tokio::test]
+async fn series_watch_history() {
+    let series = SERIES.get().await.unwrap();
+
+    let watch_history = series.watch_history().await;
+    assert_result!(watch_history);
+    assert!(watch_history
+        .unwrap()
+        .iter()
+        .all(|entry| entry.parent_id == series.id))
+}
+
+#[tokio::test]
+async fn series_progress() {
+    let progress = SERIES.get().await.unwrap().progress().await;
+
+    assert_result!(progress);
+    assert!((0.0..=1.0).contains(&progress.unwrap()))
+}
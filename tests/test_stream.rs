@@ -1,7 +1,7 @@
 use crate::utils::Store;
 use crate::utils::SESSION;
-use crunchyroll_rs::media::{Media, Stream, VariantData, VariantSegment};
-use crunchyroll_rs::Episode;
+use crunchyroll_rs::media::{Media, Stream, StreamProtocol, VariantData, VariantSegment};
+use crunchyroll_rs::{Episode, Locale};
 use rand::seq::SliceRandom;
 
 mod utils;
@@ -23,7 +23,7 @@ static STREAM_HLS_DATA: Store<VariantData> = Store::new(|| {
         let stream = STREAM.get().await?;
         let mut hls_streams = stream.hls_streaming_data(None).await?;
 
-        hls_streams.sort_by(|a, b| a.resolution.width.cmp(&b.resolution.width));
+        hls_streams.sort_by_key(|a| a.resolution.width);
 
         Ok(hls_streams[0].clone())
     })
@@ -34,7 +34,7 @@ static STREAM_DASH_DATA: Store<VariantData> = Store::new(|| {
         let stream = STREAM.get().await?;
         let mut dash_streams = stream.dash_streaming_data(None).await?.0;
 
-        dash_streams.sort_by(|a, b| a.resolution.width.cmp(&b.resolution.width));
+        dash_streams.sort_by_key(|a| a.resolution.width);
 
         Ok(dash_streams[0].clone())
     })
@@ -157,3 +157,109 @@ async fn alternative_stream_from_id() {
 async fn alternative_stream_versions() {
     assert_result!(ALTERNATIVE_STREAM.get().await.unwrap().versions().await)
 }
+
+#[tokio::test]
+async fn stream_subtitle_texts() {
+    for text in STREAM
+        .get()
+        .await
+        .unwrap()
+        .subtitle_texts()
+        .await
+        .into_values()
+    {
+        assert_result!(text)
+    }
+}
+
+#[tokio::test]
+async fn stream_subtitle_suggested_filename() {
+    for (locale, subtitle) in &STREAM.get().await.unwrap().subtitles {
+        assert_eq!(
+            subtitle.suggested_filename("Show.S01E01"),
+            format!("Show.S01E01.{}.{}", locale, subtitle.format)
+        )
+    }
+}
+
+#[tokio::test]
+async fn stream_recommended_subtitle() {
+    let stream = STREAM.get().await.unwrap();
+
+    if let Some(subtitle) = stream.recommended_subtitle(&stream.audio_locale, &[Locale::en_US]) {
+        assert!([Locale::en_US, stream.audio_locale.clone()].contains(&subtitle.locale))
+    }
+}
+
+#[test]
+fn stream_expires_at() {
+    // policy which expires at 2023-11-14T22:13:20Z
+    let policy = "eyJTdGF0ZW1lbnQiOlt7IlJlc291cmNlIjoiaHR0cHM6Ly9leGFtcGxlLmNvbS8qIiwiQ29uZGl0aW9uIjp7IkRhdGVMZXNzVGhhbiI6eyJBV1M6RXBvY2hUaW1lIjoxNzAwMDAwMDAwfX19XX0_";
+    let stream: Stream = serde_json::from_value(serde_json::json!({
+        "media_id": "GRDKJZ81Y",
+        "audio_locale": "ja-JP",
+        "subtitles": {},
+        "closed_captions": {},
+        "variants": {
+            "adaptive_hls": {
+                "": {
+                    "hardsub_locale": "",
+                    "url": format!("https://example.com/master.m3u8?Policy={policy}&Signature=x&Key-Pair-Id=x")
+                }
+            }
+        },
+        "versions": null,
+        "captions": {},
+        "bifs": []
+    }))
+    .unwrap();
+
+    assert_eq!(
+        stream.expires_at(),
+        chrono::DateTime::from_timestamp(1700000000, 0)
+    );
+    assert!(stream.is_expired())
+}
+
+#[cfg(feature = "hls-stream")]
+#[tokio::test]
+async fn stream_quality_ladder_hls_only() {
+    let mut stream = STREAM.get().await.unwrap().clone();
+    for variants in stream.variants.values_mut() {
+        variants.adaptive_dash = None
+    }
+
+    let ladder = stream.quality_ladder(None, StreamProtocol::Dash).await;
+    assert_result!(ladder);
+    let ladder = ladder.unwrap();
+    assert!(!ladder.is_empty());
+    assert!(ladder.iter().all(|q| q.protocol == StreamProtocol::Hls));
+    assert!(ladder
+        .windows(2)
+        .all(|w| w[0].resolution.width >= w[1].resolution.width))
+}
+
+#[cfg(feature = "dash-stream")]
+#[tokio::test]
+async fn stream_quality_ladder_dash_only() {
+    let mut stream = STREAM.get().await.unwrap().clone();
+    for variants in stream.variants.values_mut() {
+        variants.adaptive_hls = None
+    }
+
+    let ladder = stream.quality_ladder(None, StreamProtocol::Hls).await;
+    assert_result!(ladder);
+    let ladder = ladder.unwrap();
+    assert!(!ladder.is_empty());
+    assert!(ladder.iter().all(|q| q.protocol == StreamProtocol::Dash))
+}
+
+#[tokio::test]
+async fn stream_quality_ladder_no_variants() {
+    let mut stream = STREAM.get().await.unwrap().clone();
+    stream.variants.clear();
+
+    let ladder = stream.quality_ladder(None, StreamProtocol::Hls).await;
+    assert_result!(ladder);
+    assert!(ladder.unwrap().is_empty())
+}
@@ -0,0 +1,94 @@
+use crunchyroll_rs::error::Error;
+use crunchyroll_rs::media::Subtitle;
+use serde_json::json;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+
+const ASS: &str = "[Script Info]\nTitle: Test\nScriptType: v4.00+\n";
+
+/// Serve `body` once on a local port and return a subtitle which points to it.
+fn serve_subtitle(body: Vec<u8>) -> Subtitle {
+    serve_subtitle_with(body, true)
+}
+
+/// Like [`serve_subtitle`], but the `Content-Length` header is only sent if `content_length` is
+/// true. Without it, the body is terminated by closing the connection.
+fn serve_subtitle_with(body: Vec<u8>, content_length: bool) -> Subtitle {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut buf = [0; 1024];
+        let _ = stream.read(&mut buf).unwrap();
+        let mut header = "HTTP/1.1 200 OK\r\nConnection: close\r\n".to_string();
+        if content_length {
+            header.push_str(&format!("Content-Length: {}\r\n", body.len()))
+        }
+        header.push_str("\r\n");
+        // the client may close the connection early, e.g. if the body is too large
+        let _ = stream
+            .write_all(header.as_bytes())
+            .and_then(|_| stream.write_all(&body));
+    });
+
+    serde_json::from_value(json!({
+        "locale": "en-US",
+        "url": format!("http://{addr}/subtitle.ass"),
+        "format": "ass"
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn subtitle_text_strips_bom() {
+    let mut body = b"\xEF\xBB\xBF".to_vec();
+    body.extend_from_slice(ASS.as_bytes());
+    let subtitle = serve_subtitle(body);
+
+    let text = subtitle.text().await.unwrap();
+    assert_eq!(text, ASS)
+}
+
+#[tokio::test]
+async fn subtitle_write_to_strips_bom() {
+    let mut body = b"\xEF\xBB\xBF".to_vec();
+    body.extend_from_slice(ASS.as_bytes());
+    let subtitle = serve_subtitle(body);
+
+    let mut written = vec![];
+    subtitle.write_to(&mut written).await.unwrap();
+    assert_eq!(written, ASS.as_bytes())
+}
+
+#[tokio::test]
+async fn subtitle_text_without_bom() {
+    let subtitle = serve_subtitle(ASS.as_bytes().to_vec());
+
+    let text = subtitle.text().await.unwrap();
+    assert_eq!(text, ASS)
+}
+
+/// Default of `CrunchyrollBuilder::max_asset_size`, subtitles which are created without a session
+/// are using it.
+const DEFAULT_MAX_ASSET_SIZE: usize = 8 * 1024 * 1024;
+
+#[tokio::test]
+async fn subtitle_exceeds_max_asset_size() {
+    let subtitle = serve_subtitle(vec![b'a'; DEFAULT_MAX_ASSET_SIZE + 1]);
+
+    assert!(matches!(subtitle.text().await, Err(Error::Decode { .. })))
+}
+
+#[tokio::test]
+async fn subtitle_exceeds_max_asset_size_without_content_length() {
+    let subtitle = serve_subtitle_with(vec![b'a'; DEFAULT_MAX_ASSET_SIZE + 1], false);
+
+    assert!(matches!(subtitle.text().await, Err(Error::Decode { .. })))
+}
+
+#[tokio::test]
+async fn subtitle_within_max_asset_size() {
+    let subtitle = serve_subtitle_with(vec![b'a'; DEFAULT_MAX_ASSET_SIZE], false);
+
+    assert_eq!(subtitle.text().await.unwrap().len(), DEFAULT_MAX_ASSET_SIZE)
+}
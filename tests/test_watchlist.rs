@@ -1,6 +1,7 @@
 use crate::utils::{Store, SESSION};
-use crunchyroll_rs::list::WatchlistOptions;
+use crunchyroll_rs::list::{ExportFormat, WatchlistOptions};
 use crunchyroll_rs::Series;
+use futures_util::StreamExt;
 
 mod utils;
 
@@ -18,6 +19,35 @@ async fn watchlist() {
     assert_result!(crunchy.watchlist(WatchlistOptions::default()).await)
 }
 
+#[tokio::test]
+async fn export_watchlist() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut csv = vec![];
+    let result = crunchy.export_watchlist(&mut csv, ExportFormat::Csv).await;
+    assert_result!(result);
+    assert!(String::from_utf8(csv)
+        .unwrap()
+        .starts_with("id,title,type,progress,date_added\n"));
+
+    let mut json_lines = vec![];
+    let result = crunchy
+        .export_watchlist(&mut json_lines, ExportFormat::JsonLines)
+        .await;
+    assert_result!(result);
+    for line in String::from_utf8(json_lines).unwrap().lines() {
+        assert!(serde_json::from_str::<serde_json::Value>(line).is_ok())
+    }
+}
+
+#[tokio::test]
+async fn new_episodes() {
+    let crunchy = SESSION.get().await.unwrap();
+    if let Some(episode) = crunchy.new_episodes().next().await {
+        assert_result!(episode)
+    }
+}
+
 #[tokio::test]
 async fn add_to_watchlist() {
     let series = SERIES.get().await.unwrap();